@@ -0,0 +1,85 @@
+use aoc::{Error, Solution};
+
+pub struct Day01;
+
+aoc::register!(Day01);
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<u32>, Vec<u32>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut list_1: Vec<u32> = Vec::new();
+        let mut list_2: Vec<u32> = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let splitted: Vec<&str> = line.split_whitespace().collect();
+
+            let [l1, l2] = splitted[..] else {
+                return Err(Error::parse(index + 1, 1, "Expected two location IDs"));
+            };
+
+            list_1.push(parse_location_id(index + 1, line, l1)?);
+            list_2.push(parse_location_id(index + 1, line, l2)?);
+        }
+
+        list_1.sort();
+        list_2.sort();
+
+        Ok((list_1, list_2))
+    }
+
+    fn part1((list_1, list_2): &Self::Input) -> Result<u32, Error> {
+        Ok(list_1
+            .iter()
+            .zip(list_2.iter())
+            .fold(0, |acc, (l1, l2)| acc + l1.abs_diff(*l2)))
+    }
+
+    fn part2((list_1, list_2): &Self::Input) -> Result<u32, Error> {
+        let mut similarities = 0;
+        list_1.iter().for_each(|l| {
+            // Find the number of occurences in l2
+            let occurences = list_2.iter().filter(|&x| x == l).count() as u32;
+            similarities += occurences * l;
+        });
+
+        Ok(similarities)
+    }
+}
+
+fn parse_location_id(line_number: usize, line: &str, id: &str) -> Result<u32, Error> {
+    id.parse::<u32>()
+        .map_err(|e| Error::at(line_number, line, id, format!("Invalid location ID: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let input = Day01::parse("20   17\n10   11\n0   5").unwrap();
+        assert_eq!(Day01::part1(&input).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_parse_invalid_id() {
+        let error = Day01::parse("20   17\n10   1x").unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: 6,
+                ..
+            }
+        ));
+    }
+
+    aoc::readme_tests!(Day01);
+}
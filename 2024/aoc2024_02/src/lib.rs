@@ -0,0 +1,198 @@
+use aoc::{Error, Solution};
+
+pub struct Day02;
+
+aoc::register!(Day02);
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = ReportList;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let reports = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Report::parse(index + 1, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(ReportList(reports))
+    }
+
+    fn part1(report_list: &Self::Input) -> Result<u16, Error> {
+        Ok(resolve(report_list))
+    }
+
+    fn part2(report_list: &Self::Input) -> Result<u16, Error> {
+        Ok(resolve_dampener(report_list))
+    }
+}
+
+fn resolve(report_list: &ReportList) -> u16 {
+    report_list.0.iter().fold(
+        0,
+        |acc, report| {
+            if is_report_safe(report) {
+                acc + 1
+            } else {
+                acc
+            }
+        },
+    )
+}
+
+fn resolve_dampener(report_list: &ReportList) -> u16 {
+    report_list.0.iter().fold(0, |acc, report| {
+        let mut sum = 0;
+
+        if is_report_safe(report) {
+            sum += 1;
+        } else {
+            for i in 0..report.0.len() {
+                let mut r = report.0.clone();
+                r.remove(i);
+
+                if is_report_safe(&Report(r)) {
+                    sum += 1;
+                    break;
+                }
+            }
+        }
+
+        acc + sum
+    })
+}
+
+#[derive(Debug)]
+pub struct Report(Vec<u16>);
+pub struct ReportList(Vec<Report>);
+
+impl Report {
+    /// Reads the levels of the report on the given line.
+    pub fn parse(line_number: usize, line: &str) -> Result<Self, Error> {
+        let levels = line
+            .split_whitespace()
+            .map(|level| {
+                level
+                    .parse()
+                    .map_err(|e| Error::at(line_number, line, level, format!("Invalid level: {e}")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Report(levels))
+    }
+}
+
+fn is_report_safe(report: &Report) -> bool {
+    let decr_or_incr = decreasing_or_increasing(&report.0);
+    let adjacent = adjacent_levels(&report.0);
+
+    decr_or_incr && adjacent
+}
+
+fn decreasing_or_increasing(levels: &[u16]) -> bool {
+    let increasing = levels.iter().is_sorted_by(|a, b| a <= b);
+    let decreasing = levels.iter().is_sorted_by(|a, b| a >= b);
+
+    increasing || decreasing
+}
+
+fn adjacent_levels(levels: &[u16]) -> bool {
+    let l1 = levels.iter();
+    let l2 = levels.iter().skip(1);
+
+    l1.zip(l2)
+        .fold(true, |acc, (l1, l2)| acc && adjacent(*l1, *l2))
+}
+
+fn adjacent(l1: u16, l2: u16) -> bool {
+    let diff = l1.abs_diff(l2);
+    (1..=3).contains(&diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_adjacent_levels() {
+        let v = vec![1, 2, 3, 4];
+        assert!(adjacent_levels(&v));
+
+        let v = vec![1, 1, 2, 3];
+        assert!(!adjacent_levels(&v));
+
+        let v = vec![1, 5, 6];
+        assert!(!adjacent_levels(&v));
+
+        let v = vec![63, 67, 70, 77, 78, 81];
+        assert!(!adjacent_levels(&v));
+    }
+
+    #[test]
+    fn test_decreasing_or_increasing() {
+        let v1 = vec![1, 2, 3];
+        assert!(decreasing_or_increasing(&v1));
+
+        let v2 = vec![3, 2, 1];
+        assert!(decreasing_or_increasing(&v2));
+
+        let v3 = vec![1, 3, 2];
+        assert!(!decreasing_or_increasing(&v3));
+    }
+
+    #[test]
+    fn test_adjacent() {
+        assert!(!adjacent(1, 1));
+        assert!(adjacent(1, 2));
+        assert!(!adjacent(1, 7));
+    }
+
+    #[test]
+    fn test_is_report_safe_true() {
+        let input = "7 6 4 2 1";
+        let report = Report::parse(1, input).unwrap();
+        assert!(is_report_safe(&report));
+    }
+
+    #[test]
+    fn test_is_report_safe_false() {
+        let input = "1 2 7 8 9";
+        let report = Report::parse(1, input).unwrap();
+        assert!(!is_report_safe(&report));
+    }
+
+    #[test]
+    fn test_resolve_different_length() {
+        let lines = [
+            "7 6 4 2 1",       // Safe because the levels are all decreasing by 1 or 2.
+            "1 2 7 8 9",       // Unsafe because 2 7 is an increase of 5.
+            "9 7 6 2 1",       // Unsafe because 6 2 is a decrease of 4.
+            "1 3 2 4 5",       // Unsafe because 1 3 is increasing but 3 2 is decreasing.
+            "8 6 4 4 1",       // Unsafe because 4 4 is neither an increase or a decrease.
+            "1 3 6 7 9 11 12", // Safe because the levels are all increasing by 1, 2, or 3.
+        ];
+
+        let report_list = Day02::parse(&lines.join("\n")).unwrap();
+        assert_eq!(Day02::part1(&report_list).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Day02::parse("7 6 4\n1 2 x 8"),
+            Err(Error::Parse {
+                line: 2,
+                column: 5,
+                ..
+            })
+        ));
+    }
+
+    aoc::readme_tests!(Day02);
+}
@@ -0,0 +1,70 @@
+use aoc::{Error, Solution};
+
+pub struct Day03;
+
+aoc::register!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(buffer: &Self::Input) -> Result<i32, Error> {
+        Ok(resolve_all(buffer))
+    }
+
+    fn part2(buffer: &Self::Input) -> Result<i32, Error> {
+        Ok(resolve(buffer))
+    }
+}
+
+/// Sums every `mul` instruction, ignoring the `do()` and `don't()` conditionals.
+fn resolve_all(buffer: &str) -> i32 {
+    let regex = regex::Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+
+    regex.captures_iter(buffer).fold(0, |acc, captures| {
+        let a = captures.get(1).unwrap().as_str().parse::<i32>().unwrap();
+        let b = captures.get(2).unwrap().as_str().parse::<i32>().unwrap();
+
+        acc + a * b
+    })
+}
+
+fn resolve(buffer: &str) -> i32 {
+    let regex = regex::Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+
+    regex.find_iter(buffer).fold(0, |acc, m| {
+        let slice = &buffer[0..m.start()];
+        let dont = slice.rfind("don't()");
+        let do_ = slice.rfind("do()");
+
+        let regex = regex::Regex::new(r"(\d+),(\d+)").unwrap();
+        let captures = regex.captures(m.as_str()).unwrap();
+
+        let a = captures.get(1).unwrap().as_str().parse::<i32>().unwrap();
+        let b = captures.get(2).unwrap().as_str().parse::<i32>().unwrap();
+
+        if (do_.is_none() && dont.is_none())
+            || (do_.is_some() && dont.is_none())
+            || (do_.is_some() && dont.is_some() && do_.unwrap() > dont.unwrap())
+        {
+            return acc + a * b;
+        }
+
+        acc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::readme_tests!(Day03);
+}
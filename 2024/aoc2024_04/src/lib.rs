@@ -0,0 +1,134 @@
+use aoc::{Error, Grid, Solution};
+
+pub struct Day04;
+
+aoc::register!(Day04);
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(matrix: &Self::Input) -> Result<i32, Error> {
+        Ok(resolve(matrix))
+    }
+
+    fn part2(matrix: &Self::Input) -> Result<i32, Error> {
+        Ok(resolve_part2(matrix))
+    }
+}
+
+// Find all the 'A'.
+// When found check if all diagonals are M or S.
+// If so, check if the diagonal pairs are 'M' and 'S'.
+
+fn resolve_part2(matrix: &Grid<char>) -> i32 {
+    matrix
+        .cells()
+        .filter(|&(position, value)| *value == 'A' && are_diagonals_m_s(matrix, position))
+        .count() as i32
+}
+
+fn are_diagonals_m_s(matrix: &Grid<char>, position: (usize, usize)) -> bool {
+    // Cells on the edges miss some of their diagonals.
+    let corner = |offset| matrix.offset(position, offset).map(|corner| matrix[corner]);
+
+    match (
+        corner((-1, -1)),
+        corner((-1, 1)),
+        corner((1, -1)),
+        corner((1, 1)),
+    ) {
+        (Some(top_left), Some(top_right), Some(bottom_left), Some(bottom_right)) => {
+            check_pair(top_left, bottom_right) && check_pair(top_right, bottom_left)
+        }
+        _ => false,
+    }
+}
+
+fn check_pair(c1: char, c2: char) -> bool {
+    c1 == 'M' && c2 == 'S' || c1 == 'S' && c2 == 'M'
+}
+
+fn resolve(matrix: &Grid<char>) -> i32 {
+    let mut sum = 0;
+
+    sum += diagonal(matrix);
+
+    // Do the horizontal and vertical count
+    sum += horizontal_count(matrix);
+    sum += horizontal_count(&matrix.rotate());
+
+    sum
+}
+
+fn horizontal_count(matrix: &Grid<char>) -> i32 {
+    matrix.rows().map(|row| line_count(row.iter())).sum()
+}
+
+fn diagonal(matrix: &Grid<char>) -> i32 {
+    let positive: i32 = matrix.diagonals().map(line_count).sum();
+    let negative: i32 = matrix.anti_diagonals().map(line_count).sum();
+
+    positive + negative
+}
+
+/// Counts the XMAS in a line, read in both directions.
+fn line_count<'a>(line: impl Iterator<Item = &'a char>) -> i32 {
+    let line: String = line.collect();
+
+    (line.matches("XMAS").count() + line.matches("SAMX").count()) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(input: &str) -> Grid<char> {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let lines = ["X...X", ".M.M.", "..A..", ".S.S."];
+
+        let matrix = matrix(&lines.join("\n"));
+        assert_eq!(diagonal(&matrix), 2);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let lines = ["XXMAS", "MAAAA", "AAAAA", "SAAAA"];
+
+        let matrix = matrix(&lines.join("\n")).rotate();
+
+        assert_eq!(matrix.row(0), ['S', 'A', 'M', 'X']);
+        assert_eq!(matrix.row(1), ['A', 'A', 'A', 'X']);
+        assert_eq!(matrix.row(2), ['A', 'A', 'A', 'M']);
+        assert_eq!(matrix.row(3), ['A', 'A', 'A', 'A']);
+        assert_eq!(matrix.row(4), ['A', 'A', 'A', 'S']);
+    }
+
+    #[test]
+    fn test_horizontal_count() {
+        let lines = ["MSAMXMSMSAXMASAMX"];
+        let matrix = matrix(&lines.join("\n"));
+        assert_eq!(horizontal_count(&matrix), 3);
+    }
+
+    #[test]
+    fn test_resolve_1() {
+        let lines = ["X...SA", "M.XMAS", "A...MA", "SSAMXM", "S.A.XX"];
+
+        assert_eq!(resolve(&matrix(&lines.join("\n"))), 5);
+    }
+
+    aoc::readme_tests!(Day04);
+}
@@ -0,0 +1,457 @@
+use aoc::{Error, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Day05;
+
+aoc::register!(Day05);
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = Manual;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (ordering, updates) = parse_input(input)?;
+
+        Ok(Manual {
+            rules: Rules::new(&ordering),
+            updates,
+        })
+    }
+
+    /// Loop through each update and check that no page has to come before
+    /// one of the previous pages.
+    fn part1(manual: &Self::Input) -> Result<i32, Error> {
+        let valid_updates = filter_correct_updates(&manual.rules, &manual.updates);
+        Ok(sum_middle_pages(&valid_updates))
+    }
+
+    /// Order the pages of the invalid updates, which fails if the rules
+    /// between the pages of one of them form a cycle.
+    fn part2(manual: &Self::Input) -> Result<i32, Error> {
+        let invalid_updates = filter_incorrect_updates(&manual.rules, &manual.updates);
+        let ordered_updates = order_pages(&manual.rules, &invalid_updates)
+            .map_err(|cycle| Error::NoAnswer(cycle.to_string()))?;
+
+        Ok(sum_middle_pages(&ordered_updates))
+    }
+}
+
+/// The rules and updates of the safety manual.
+pub struct Manual {
+    rules: Rules,
+    updates: Updates,
+}
+
+type PageOrdering = Vec<(i32, i32)>;
+type Update = Vec<i32>;
+type Updates = Vec<Update>;
+
+/// The page ordering rules, indexed by the page that must come first.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// The pages that must come after each page.
+    after: HashMap<i32, HashSet<i32>>,
+}
+
+impl Rules {
+    fn new(ordering: &[(i32, i32)]) -> Self {
+        let mut after: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+        for &(before, page) in ordering {
+            after.entry(before).or_default().insert(page);
+        }
+
+        Self { after }
+    }
+
+    /// Returns whether a rule says that `before` must come before `after`.
+    fn must_precede(&self, before: i32, after: i32) -> bool {
+        self.after
+            .get(&before)
+            .is_some_and(|pages| pages.contains(&after))
+    }
+}
+
+/// A rule an update breaks: `before` must come before `after`, but comes
+/// after it. Positions are indexes in the update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub before: i32,
+    pub after: i32,
+    pub before_position: usize,
+    pub after_position: usize,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rule {}|{} is broken, page {} at position {} comes before page {} at position {}",
+            self.before,
+            self.after,
+            self.after,
+            self.after_position + 1,
+            self.before,
+            self.before_position + 1,
+        )
+    }
+}
+
+/// Returns the first rule each invalid update breaks, along with the index of
+/// the update.
+pub fn violations(manual: &Manual) -> Vec<(usize, Violation)> {
+    manual
+        .updates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, update)| find_violation(&manual.rules, update).map(|v| (index, v)))
+        .collect()
+}
+
+/// Returns the first rule the update breaks, scanning its pages in order, if
+/// any.
+fn find_violation(rules: &Rules, update: &Update) -> Option<Violation> {
+    update
+        .iter()
+        .enumerate()
+        .find_map(|(after_position, &after)| {
+            let before_position = update[after_position + 1..]
+                .iter()
+                .position(|&before| rules.must_precede(before, after))?;
+
+            Some(Violation {
+                before: update[after_position + 1 + before_position],
+                after,
+                before_position: after_position + 1 + before_position,
+                after_position,
+            })
+        })
+}
+
+/// Pages of an update whose rules form a cycle: each page must come before
+/// the next one, and the last one before the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cycle(Vec<i32>);
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pages: Vec<String> = self
+            .0
+            .iter()
+            .chain(self.0.first())
+            .map(i32::to_string)
+            .collect();
+
+        write!(f, "The ordering rules form a cycle: {}", pages.join(" -> "))
+    }
+}
+
+/// Returns the updates with their pages ordered, or the first cycle formed by
+/// the rules between the pages of an update.
+fn order_pages(rules: &Rules, updates: &Updates) -> Result<Updates, Cycle> {
+    updates
+        .iter()
+        .map(|update| order_update(rules, update))
+        .collect()
+}
+
+/// Orders the pages of an update with a topological sort of the rules
+/// between its pages. Pages that no rule orders keep their relative order.
+fn order_update(rules: &Rules, update: &Update) -> Result<Update, Cycle> {
+    // The number of pages each page must come after.
+    let mut predecessors: Vec<usize> = update
+        .iter()
+        .map(|&page| {
+            update
+                .iter()
+                .filter(|&&before| rules.must_precede(before, page))
+                .count()
+        })
+        .collect();
+
+    let mut ordered = Vec::with_capacity(update.len());
+
+    while ordered.len() < update.len() {
+        // Take the first page that no remaining page must come before.
+        let Some(index) = predecessors.iter().position(|&count| count == 0) else {
+            let remaining: Vec<i32> = (0..update.len())
+                .filter(|&index| predecessors[index] != usize::MAX)
+                .map(|index| update[index])
+                .collect();
+
+            return Err(find_cycle(rules, &remaining));
+        };
+
+        let page = update[index];
+        ordered.push(page);
+        // Mark the page as taken.
+        predecessors[index] = usize::MAX;
+
+        for (other, count) in update.iter().zip(&mut predecessors) {
+            if *count != usize::MAX && rules.must_precede(page, *other) {
+                *count -= 1;
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Finds a cycle among the remaining pages of a topological sort, every one
+/// of them having to come after another one.
+fn find_cycle(rules: &Rules, remaining: &[i32]) -> Cycle {
+    let mut path = vec![remaining[0]];
+
+    loop {
+        let page = path[path.len() - 1];
+        let before = *remaining
+            .iter()
+            .find(|&&before| rules.must_precede(before, page))
+            .expect("Every remaining page comes after another one");
+
+        // Walking backwards, the path loops once it reaches a page again.
+        if let Some(start) = path.iter().position(|&p| p == before) {
+            let mut cycle = path.split_off(start);
+            cycle.reverse();
+
+            return Cycle(cycle);
+        }
+
+        path.push(before);
+    }
+}
+
+fn sum_middle_pages(updates: &Updates) -> i32 {
+    updates.iter().fold(0, |acc, u| {
+        let len = u.len();
+        let value = u[len / 2];
+
+        acc + value
+    })
+}
+
+fn filter_incorrect_updates(rules: &Rules, updates: &Updates) -> Updates {
+    updates
+        .iter()
+        .filter(|update| !is_valid_update(rules, update))
+        .cloned()
+        .collect()
+}
+
+fn filter_correct_updates(rules: &Rules, updates: &Updates) -> Updates {
+    updates
+        .iter()
+        .filter(|update| is_valid_update(rules, update))
+        .cloned()
+        .collect()
+}
+
+fn is_valid_update(rules: &Rules, update: &Update) -> bool {
+    find_violation(rules, update).is_none()
+}
+
+fn parse_input(intput: &str) -> Result<(PageOrdering, Updates), Error> {
+    // Split the black line
+    let mut parts = intput.split("\n\n");
+
+    let ordering_section = parts.next().unwrap_or_default();
+    let updates_section = parts
+        .next()
+        .ok_or_else(|| Error::parse(ordering_section.lines().count() + 1, 1, "Missing updates"))?;
+
+    let ordering = parse_page_ordering(ordering_section)?;
+    let updates = parse_updates(updates_section, ordering.len() + 2)?;
+
+    Ok((ordering, updates))
+}
+
+fn parse_page_ordering(input: &str) -> Result<PageOrdering, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let Some((page, order)) = line.split_once("|") else {
+                return Err(Error::parse(index + 1, 1, "Expected a `X|Y` rule"));
+            };
+
+            let page = parse_page(index + 1, line, page)?;
+            let order = parse_page(index + 1, line, order)?;
+            Ok((page, order))
+        })
+        .collect()
+}
+
+/// Parses the updates section, whose first line is `first_line` in the input.
+fn parse_updates(input: &str, first_line: usize) -> Result<Updates, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split(",")
+                .map(|part| parse_page(first_line + index, line, part))
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_page(line_number: usize, line: &str, page: &str) -> Result<i32, Error> {
+    let page = page.trim();
+
+    page.parse()
+        .map_err(|e| Error::at(line_number, line, page, format!("Invalid page number: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_pages() {
+        let rules = Rules::new(&[(1, 2), (1, 5), (2, 5)]);
+        let updates = vec![
+            vec![1, 2, 5], // valid
+            vec![1, 5, 2], // invalid
+            vec![2, 5, 1], // invalid
+        ];
+
+        let ordered = order_pages(&rules, &updates);
+
+        assert_eq!(
+            ordered,
+            Ok(vec![vec![1, 2, 5], vec![1, 2, 5], vec![1, 2, 5]])
+        );
+    }
+
+    #[test]
+    fn test_order_update_cycle() {
+        let rules = Rules::new(&[(1, 2), (2, 5), (5, 3), (3, 2), (4, 1)]);
+
+        // The rules between 2, 3 and 5 form a cycle...
+        let cycle = order_update(&rules, &vec![4, 2, 3, 5]).unwrap_err();
+        assert_eq!(cycle, Cycle(vec![5, 3, 2]));
+        assert_eq!(
+            cycle.to_string(),
+            "The ordering rules form a cycle: 5 -> 3 -> 2 -> 5"
+        );
+
+        // ...but not between 1, 2 and 5.
+        assert_eq!(order_update(&rules, &vec![5, 2, 1]), Ok(vec![1, 2, 5]));
+    }
+
+    #[test]
+    fn test_violations() {
+        let input = Day05::parse(&aoc::examples::example(
+            include_str!("../README.md"),
+            "example1",
+        ))
+        .unwrap();
+
+        let violations = violations(&input);
+
+        assert_eq!(
+            violations
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert_eq!(
+            violations[0].1,
+            Violation {
+                before: 97,
+                after: 75,
+                before_position: 1,
+                after_position: 0,
+            }
+        );
+        assert_eq!(
+            violations[0].1.to_string(),
+            "rule 97|75 is broken, page 75 at position 1 comes before page 97 at position 2"
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        let manual = Day05::parse("1|2\n2|1\n3|1\n\n3,1\n1,3,2").unwrap();
+
+        // Only the ordering of the second update fails.
+        assert_eq!(Day05::part1(&manual).unwrap(), 1);
+        assert_eq!(
+            Day05::part2(&manual).unwrap_err().to_string(),
+            "no answer: The ordering rules form a cycle: 2 -> 1 -> 2"
+        );
+    }
+
+    #[test]
+    fn test_sum_middle_pages() {
+        let updates = vec![
+            vec![10, 10, 1, 0, 0],
+            vec![19, 2, 3, 1, 2],
+            vec![0, 3, 4, 7, 2],
+            vec![1, 4, 3],
+        ];
+
+        let expected = 1 + 3 + 4 + 4;
+        assert_eq!(sum_middle_pages(&updates), expected);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "0|0\n1|1\n\n0,1,23";
+        let (ordering, updates) = parse_input(input).unwrap();
+
+        assert_eq!(ordering, vec![(0, 0), (1, 1)]);
+        assert_eq!(updates, vec![vec![0, 1, 23]]);
+    }
+
+    #[test]
+    fn test_parse_input_invalid_page() {
+        let input = "0|0\n1|1\n\n0,1,23\n4,x";
+
+        assert!(matches!(
+            parse_input(input),
+            Err(Error::Parse {
+                line: 5,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_is_valid_update_true_1() {
+        let rules = Rules::new(&[(1, 2), (1, 5), (2, 5)]);
+        let update = vec![1, 2, 5];
+
+        assert!(is_valid_update(&rules, &update));
+    }
+
+    #[test]
+    fn test_is_valid_update_false_1() {
+        let rules = Rules::new(&[(1, 2), (5, 1), (2, 5)]);
+        let update = vec![1, 2, 5];
+
+        assert!(!is_valid_update(&rules, &update));
+    }
+
+    #[test]
+    fn test_filter_valid_updates() {
+        let rules = Rules::new(&[(1, 2), (1, 5), (2, 5)]);
+        let updates = vec![
+            vec![1, 2, 5], // valid
+            vec![1, 5, 2], // invalid
+            vec![2, 5, 1], // invalid
+        ];
+
+        let valid_updates = filter_correct_updates(&rules, &updates);
+
+        assert_eq!(valid_updates, vec![vec![1, 2, 5]]);
+    }
+
+    aoc::readme_tests!(Day05);
+}
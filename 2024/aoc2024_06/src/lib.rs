@@ -0,0 +1,585 @@
+use aoc::geom::{Direction, Point};
+use aoc::{animation, Error, Grid, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub struct Day06;
+
+aoc::register!(Day06);
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Lab::new(input.parse()?)
+    }
+
+    fn part1(lab: &Self::Input) -> Result<usize, Error> {
+        Ok(lab.patrolled_cells()?.len())
+    }
+
+    /// Add an obstacle on each cell of the patrol but the starting one: an
+    /// obstacle anywhere else is never met. With it, the guard loops once she
+    /// turns twice at the same place in the same direction.
+    fn part2(lab: &Self::Input) -> Result<usize, Error> {
+        Ok(lab.loop_obstacles()?.len())
+    }
+}
+
+const OBSTACLE: char = '#';
+const EMPTY: char = '.';
+/// An obstacle added to the lab, in the renderings of the patrol.
+const ADDED: char = 'O';
+
+type Cell = (usize, usize);
+
+/// The lab the guard patrols.
+pub struct Lab {
+    obstacles: Grid<bool>,
+    guard: Guard,
+    /// For each cell and orthogonal direction, the cell where the guard
+    /// stops in front of the next obstacle, if she does not leave the lab.
+    jumps: Grid<[Option<Cell>; 4]>,
+}
+
+/// The guard, at a given cell and facing a given direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub position: Cell,
+    pub direction: Direction,
+}
+
+impl Guard {
+    /// Takes a step forward, or turns right in front of an obstacle, the
+    /// added one included. Returns `None` once the guard leaves the lab.
+    fn step(self, lab: &Lab, obstacle: Option<Cell>) -> Option<Guard> {
+        let next = lab.obstacles.step(self.position, self.direction)?;
+
+        if lab.obstacles[next] || obstacle == Some(next) {
+            Some(Guard {
+                position: self.position,
+                direction: self.direction.turn_right(),
+            })
+        } else {
+            Some(Guard {
+                position: next,
+                direction: self.direction,
+            })
+        }
+    }
+}
+
+impl Lab {
+    fn new(map: Grid<char>) -> Result<Self, Error> {
+        let mut guard = None;
+
+        for (cell, &c) in map.cells() {
+            match c {
+                OBSTACLE | EMPTY => {}
+                _ => match Direction::try_from(c) {
+                    Ok(direction) if guard.is_none() && !direction.is_diagonal() => {
+                        guard = Some(Guard {
+                            position: cell,
+                            direction,
+                        });
+                    }
+                    _ => {
+                        return Err(Error::parse(
+                            cell.0 + 1,
+                            cell.1 + 1,
+                            format!("Unexpected {c:?}, expected `.`, `#` or a single guard"),
+                        ));
+                    }
+                },
+            }
+        }
+
+        let guard = guard.ok_or_else(|| Error::parse(1, 1, "No guard found in the lab"))?;
+        let obstacles = map.map(|&c| c == OBSTACLE);
+        let jumps = jumps(&obstacles);
+
+        Ok(Self {
+            obstacles,
+            guard,
+            jumps,
+        })
+    }
+
+    /// The guard at the start of her patrol.
+    pub fn guard(&self) -> Guard {
+        self.guard
+    }
+
+    pub fn width(&self) -> usize {
+        self.obstacles.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.obstacles.height()
+    }
+
+    /// Returns the cells the guard walks through before leaving the lab, in
+    /// the order of her first visit, or an error if she never leaves it.
+    pub fn patrolled_cells(&self) -> Result<Vec<Cell>, Error> {
+        let mut visited = Grid::new(self.obstacles.width(), self.obstacles.height(), false);
+        let mut turns = HashSet::new();
+        let mut cells = Vec::new();
+
+        let mut guard = Some(self.guard);
+        while let Some(current) = guard {
+            if !std::mem::replace(&mut visited[current.position], true) {
+                cells.push(current.position);
+            }
+
+            guard = current.step(self, None);
+
+            // She loops once she turns twice at the same place.
+            if guard.is_some_and(|next| next.direction != current.direction)
+                && !turns.insert(current)
+            {
+                let (row, col) = current.position;
+
+                return Err(Error::NoAnswer(format!(
+                    "The guard never leaves the lab, she loops at {}:{}",
+                    row + 1,
+                    col + 1
+                )));
+            }
+        }
+
+        Ok(cells)
+    }
+
+    /// Returns the cells where an obstacle would be met by the guard, i.e.
+    /// the patrolled ones but the starting one.
+    pub fn obstacle_candidates(&self) -> Result<Vec<Cell>, Error> {
+        let mut cells = self.patrolled_cells()?;
+        cells.retain(|&cell| cell != self.guard.position);
+
+        Ok(cells)
+    }
+
+    /// Returns the candidate obstacles making the guard loop, in the order
+    /// of the patrol. They are checked across threads with the `parallel`
+    /// feature, sharing the lab.
+    pub fn loop_obstacles(&self) -> Result<Vec<Cell>, Error> {
+        let candidates = self.obstacle_candidates()?;
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            Ok(candidates
+                .into_par_iter()
+                .filter(|&cell| self.loops_with(cell))
+                .collect())
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            Ok(candidates
+                .into_iter()
+                .filter(|&cell| self.loops_with(cell))
+                .collect())
+        }
+    }
+
+    /// Records every step of the patrol, with an obstacle added at the given
+    /// cell if any, until the guard leaves the lab or loops.
+    pub fn trace(&self, obstacle: Option<Cell>) -> Vec<Step> {
+        let mut seen = HashSet::from([self.guard]);
+        let mut steps = vec![Step {
+            guard: self.guard,
+            event: Event::Start,
+        }];
+
+        let mut current = self.guard;
+        loop {
+            let Some(next) = current.step(self, obstacle) else {
+                steps.push(Step {
+                    guard: current,
+                    event: Event::Exit,
+                });
+                return steps;
+            };
+
+            let event = if !seen.insert(next) {
+                Event::Loop
+            } else if next.direction != current.direction {
+                Event::Turn
+            } else {
+                Event::Move
+            };
+            steps.push(Step { guard: next, event });
+
+            if event == Event::Loop {
+                return steps;
+            }
+            current = next;
+        }
+    }
+
+    /// Renders the lab after each step of a trace, with the path walked so
+    /// far, the guard and the step drawn under the map.
+    pub fn frames<'a>(
+        &self,
+        steps: &'a [Step],
+        obstacle: Option<Cell>,
+    ) -> impl Iterator<Item = String> + 'a {
+        let mut canvas = self
+            .obstacles
+            .map(|&obstacle| if obstacle { OBSTACLE } else { EMPTY });
+        if let Some(cell) = obstacle.filter(|&cell| canvas.get(cell).is_some()) {
+            canvas[cell] = ADDED;
+        }
+
+        steps.iter().enumerate().map(move |(index, step)| {
+            let Guard {
+                position,
+                direction,
+            } = step.guard;
+            animation::mark(&mut canvas, position, direction);
+
+            let mut frame = canvas.clone();
+            frame[position] = direction.glyph();
+
+            format!("{frame}\nStep {index}: {step}")
+        })
+    }
+
+    /// Returns whether the guard loops once an obstacle is added at the given
+    /// cell, jumping from one obstacle to the next.
+    pub fn loops_with(&self, obstacle: Cell) -> bool {
+        let mut turns = HashSet::new();
+        let mut guard = self.guard;
+
+        while let Some(stop) = self.jump(guard, obstacle) {
+            guard = Guard {
+                position: stop,
+                direction: guard.direction,
+            };
+
+            if !turns.insert(guard) {
+                return true;
+            }
+
+            guard.direction = guard.direction.turn_right();
+        }
+
+        false
+    }
+
+    /// Returns the cell where the guard stops in front of the next obstacle,
+    /// the added one included, if she does not leave the lab.
+    fn jump(&self, guard: Guard, obstacle: Cell) -> Option<Cell> {
+        let stop = self.jumps[guard.position][index(guard.direction)];
+
+        let from = point(guard.position);
+        let offset = guard.direction.offset::<isize>();
+        let to_obstacle = point(obstacle) - from;
+
+        // The added obstacle is ahead of the guard when it lies along her
+        // direction, and stops her first when it is before the next one.
+        let ahead = to_obstacle.x * offset.y == to_obstacle.y * offset.x
+            && to_obstacle.x * offset.x + to_obstacle.y * offset.y > 0;
+        let distance = from.manhattan(point(obstacle));
+
+        match stop {
+            Some(stop) if !ahead || distance > from.manhattan(point(stop)) => Some(stop),
+            None if !ahead => None,
+            _ => (from + offset * (distance - 1)).cell(),
+        }
+    }
+}
+
+/// What the guard did at a step of her patrol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// She starts her patrol.
+    Start,
+    /// She moves forward.
+    Move,
+    /// She turns right in front of an obstacle.
+    Turn,
+    /// She is back where she was, facing the same way: she loops.
+    Loop,
+    /// She leaves the lab with her next step.
+    Exit,
+}
+
+/// A step of the patrol: the guard once it is taken, and what she did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub guard: Guard,
+    pub event: Event,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Event::Start => "start",
+            Event::Move => "move",
+            Event::Turn => "turn",
+            Event::Loop => "loop",
+            Event::Exit => "exit",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// Shows the line and column of the guard, both starting at 1, then her
+/// direction and the event, e.g. `7:5 ^ move`.
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (row, col) = self.guard.position;
+
+        write!(
+            f,
+            "{}:{} {} {}",
+            row + 1,
+            col + 1,
+            self.guard.direction,
+            self.event
+        )
+    }
+}
+
+/// Returns the index of an orthogonal direction in the jump table.
+fn index(direction: Direction) -> usize {
+    // Orthogonal directions are every other one, clockwise from up.
+    direction as usize / 2
+}
+
+fn point(cell: Cell) -> Point<isize> {
+    Point::from_cell(cell).expect("The lab is small enough")
+}
+
+/// Computes, for each cell and orthogonal direction, where the guard stops in
+/// front of the next obstacle.
+fn jumps(obstacles: &Grid<bool>) -> Grid<[Option<Cell>; 4]> {
+    let mut jumps = obstacles.map(|_| [None; 4]);
+    let cells: Vec<Cell> = obstacles.positions().collect();
+
+    for direction in Direction::ORTHOGONAL {
+        // The stop of a cell is the one of the next cell, which must be
+        // computed first: cells are visited from the edge the guard faces.
+        let ordered: Box<dyn Iterator<Item = &Cell>> = match direction {
+            Direction::Up | Direction::Left => Box::new(cells.iter()),
+            _ => Box::new(cells.iter().rev()),
+        };
+
+        for &cell in ordered {
+            jumps[cell][index(direction)] = match obstacles.step(cell, direction) {
+                None => None,
+                Some(next) if obstacles[next] => Some(cell),
+                Some(next) => jumps[next][index(direction)],
+            };
+        }
+    }
+
+    jumps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jump() {
+        let lab = Day06::parse("....#\n.....\n..^..\n.....").unwrap();
+        let guard = lab.guard();
+        let right = Guard {
+            position: (2, 2),
+            direction: Direction::Right,
+        };
+
+        assert_eq!(lab.jumps[(0, 0)][index(Direction::Right)], Some((0, 3)));
+        assert_eq!(lab.jumps[(3, 4)][index(Direction::Up)], Some((1, 4)));
+        assert_eq!(lab.jumps[(1, 1)][index(Direction::Down)], None);
+
+        // An obstacle ahead stops the guard before the edge...
+        assert_eq!(lab.jump(guard, (0, 2)), Some((1, 2)));
+        assert_eq!(lab.jump(guard, (1, 2)), Some((2, 2)));
+        assert_eq!(lab.jump(right, (2, 4)), Some((2, 3)));
+        // ...but not when behind her, aside, or past the next obstacle.
+        assert_eq!(lab.jump(guard, (3, 2)), None);
+        assert_eq!(lab.jump(guard, (0, 3)), None);
+        assert_eq!(
+            lab.jump(
+                Guard {
+                    position: (3, 4),
+                    direction: Direction::Up
+                },
+                (0, 4)
+            ),
+            Some((1, 4))
+        );
+    }
+
+    #[test]
+    fn test_loops_with() {
+        let input = aoc::examples::example(include_str!("../README.md"), "lab");
+        let lab = Day06::parse(&input).unwrap();
+
+        // The obstacles making the guard loop in the puzzle description.
+        let mut loops = lab.loop_obstacles().unwrap();
+        loops.sort();
+
+        assert_eq!(loops, [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]);
+    }
+
+    /// Walks step by step through a lab whose map has the obstacle added.
+    fn loops_brute_force(map: &Grid<char>, obstacle: Cell) -> bool {
+        let mut map = map.clone();
+        map[obstacle] = OBSTACLE;
+        let lab = Lab::new(map).unwrap();
+
+        let mut states = HashSet::new();
+        let mut guard = Some(lab.guard());
+
+        while let Some(current) = guard {
+            if !states.insert(current) {
+                return true;
+            }
+
+            guard = current.step(&lab, None);
+        }
+
+        false
+    }
+
+    #[test]
+    fn test_loops_with_brute_force() {
+        // A linear congruential generator, to scatter obstacles reproducibly.
+        let mut seed = 42_u64;
+        let mut random = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as usize
+        };
+
+        for _ in 0..50 {
+            let mut map = Grid::new(12, 9, EMPTY);
+            for _ in 0..25 {
+                map[(random() % 9, random() % 12)] = OBSTACLE;
+            }
+            let start = (random() % 9, random() % 12);
+            map[start] = '^';
+            let lab = Lab::new(map.clone()).unwrap();
+
+            for cell in map.positions().filter(|&cell| cell != start) {
+                assert_eq!(
+                    lab.loops_with(cell),
+                    loops_brute_force(&map, cell),
+                    "obstacle at {cell:?} in\n{map}"
+                );
+            }
+        }
+    }
+
+    /// With the `parallel` feature, the obstacles are checked across threads
+    /// but must be the same, in the same order, as when checked in turn.
+    #[test]
+    fn test_loop_obstacles_deterministic() {
+        let mut map: Grid<char> = aoc::examples::example(include_str!("../README.md"), "lab")
+            .parse()
+            .unwrap();
+
+        for extra in [None, Some((2, 2)), Some((5, 6))] {
+            if let Some(cell) = extra {
+                map[cell] = OBSTACLE;
+            }
+            let lab = Lab::new(map.clone()).unwrap();
+
+            let sequential: Vec<Cell> = lab
+                .obstacle_candidates()
+                .unwrap()
+                .into_iter()
+                .filter(|&cell| lab.loops_with(cell))
+                .collect();
+
+            for _ in 0..10 {
+                assert_eq!(lab.loop_obstacles().unwrap(), sequential);
+            }
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = aoc::examples::example(include_str!("../README.md"), "lab");
+        let lab = Day06::parse(&input).unwrap();
+
+        let steps = lab.trace(None);
+        let events = |event| steps.iter().filter(|step| step.event == event).count();
+
+        assert_eq!(steps[0].to_string(), "7:5 ^ start");
+        assert_eq!(steps[1].to_string(), "6:5 ^ move");
+        assert_eq!(steps.last().unwrap().to_string(), "10:8 v exit");
+        assert_eq!((events(Event::Turn), events(Event::Loop)), (10, 0));
+
+        let cells: HashSet<Cell> = steps.iter().map(|step| step.guard.position).collect();
+        assert_eq!(cells.len(), lab.patrolled_cells().unwrap().len());
+
+        // The trace ends with a loop exactly with the obstacles of part 2.
+        for cell in lab.obstacle_candidates().unwrap() {
+            let looped = lab.trace(Some(cell)).last().unwrap().event == Event::Loop;
+            assert_eq!(looped, lab.loops_with(cell), "obstacle at {cell:?}");
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let lab = Day06::parse("..#..\n.....\n..^..").unwrap();
+
+        let obstacle = Some((1, 4));
+        let steps = lab.trace(obstacle);
+        let frames: Vec<String> = lab.frames(&steps, obstacle).collect();
+
+        assert_eq!(frames.len(), steps.len());
+        assert_eq!(frames[0], "..#..\n....O\n..^..\nStep 0: 3:3 ^ start");
+        assert_eq!(frames[2], "..#..\n..>.O\n..|..\nStep 2: 2:3 > turn");
+        assert_eq!(frames[3], "..#..\n..+>O\n..|..\nStep 3: 2:4 > move");
+        assert_eq!(frames[4], "..#..\n..+vO\n..|..\nStep 4: 2:4 v turn");
+        assert_eq!(frames[5], "..#..\n..++O\n..|v.\nStep 5: 3:4 v move");
+    }
+
+    #[test]
+    fn test_guard_loops() {
+        let lab = Day06::parse(".#...\n....#\n.....\n#^...\n...#.").unwrap();
+
+        assert_eq!(
+            Day06::part1(&lab).unwrap_err().to_string(),
+            "no answer: The guard never leaves the lab, she loops at 2:2"
+        );
+        assert!(Day06::part2(&lab).is_err());
+        assert_eq!(lab.trace(None).last().unwrap().event, Event::Loop);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Day06::parse("..#\n.x."),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day06::parse("..#\n^.^"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(Day06::parse("..#\n...").is_err());
+    }
+
+    aoc::readme_tests!(Day06);
+}
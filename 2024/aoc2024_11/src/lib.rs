@@ -0,0 +1,207 @@
+use aoc::memo::Memo;
+use aoc::{Error, Solution};
+
+pub struct Day11;
+
+aoc::register!(Day11);
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Part1 = usize;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<usize, Error> {
+        blink_nth(stones.clone(), 25)
+            .map(|stones| stones.len())
+            .ok_or_else(|| too_large(25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<u128, Error> {
+        count_stones(stones, 75, &mut Cache::new()).ok_or_else(|| too_large(75))
+    }
+}
+
+/// The error of the parts when a stone or the count overflows.
+fn too_large(times: u32) -> Error {
+    Error::NoAnswer(format!(
+        "The stones of {times} blinks are too large to count"
+    ))
+}
+
+/// The number of stones a stone becomes after some blinks, keyed by the
+/// value of the stone and the number of blinks. `None` if a stone or the
+/// count does not fit.
+type Cache = Memo<(u64, u32), Option<u128>>;
+
+/// Counts the stones after blinking a given number of times, without
+/// simulating them.
+///
+/// Each stone changes independently of the others, and the stones take few
+/// distinct values, so the count of each `(value, remaining blinks)` pair is
+/// computed once. The cost grows linearly with the number of blinks.
+///
+/// Returns `None` if a stone does not fit in a `u64`, or the count in a
+/// `u128`.
+fn count_stones(stones: &[u64], times: u32, cache: &mut Cache) -> Option<u128> {
+    stones.iter().try_fold(0_u128, |total, &stone| {
+        total.checked_add(count_stone(stone, times, cache)?)
+    })
+}
+
+fn count_stone(stone: u64, times: u32, cache: &mut Cache) -> Option<u128> {
+    if times == 0 {
+        return Some(1);
+    }
+
+    cache.get_or_compute((stone, times), |cache, &(stone, times)| {
+        let (left, right) = blink_stone(stone)?;
+        let left = count_stone(left, times - 1, cache)?;
+
+        match right {
+            Some(right) => left.checked_add(count_stone(right, times - 1, cache)?),
+            None => Some(left),
+        }
+    })
+}
+
+/// Blink a given number of time on the given stones, or `None` if a stone
+/// does not fit in a `u64`.
+fn blink_nth(stones: Vec<u64>, times: u32) -> Option<Vec<u64>> {
+    let mut s = stones;
+    for _ in 0..times {
+        s = blink(s)?
+    }
+
+    Some(s)
+}
+
+// Blink and transform the stones by applying the given rules.
+fn blink(stones: Vec<u64>) -> Option<Vec<u64>> {
+    let mut s: Vec<u64> = Vec::new();
+
+    for stone in stones {
+        let (left, right) = blink_stone(stone)?;
+
+        s.push(left);
+        s.extend(right);
+    }
+
+    Some(s)
+}
+
+/// Transforms a stone into one or two stones by applying the given rules, or
+/// `None` if the new stone does not fit in a `u64`.
+fn blink_stone(stone: u64) -> Option<(u64, Option<u64>)> {
+    // rule 1
+    if stone == 0 {
+        return Some((1, None));
+    }
+
+    // rule 2
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+
+        return Some((stone / half, Some(stone % half)));
+    }
+
+    // rule 3
+    Some((stone.checked_mul(2024)?, None))
+}
+
+/// Parse the input to return a list of stones
+fn parse_input(lines: &str) -> Result<Vec<u64>, Error> {
+    let line = lines.lines().next().unwrap_or_default();
+
+    line.split_whitespace()
+        .map(|block| {
+            block
+                .parse()
+                .map_err(|e| Error::at(1, line, block, format!("Invalid stone: {e}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The stones of the example in the puzzle description.
+    fn example() -> Vec<u64> {
+        let input = aoc::examples::example(include_str!("../README.md"), "example1");
+        Day11::parse(&input).unwrap()
+    }
+
+    #[test]
+    fn test_blink() {
+        let input = vec![0, 1, 10, 99, 999];
+        let output = vec![1, 2024, 1, 0, 9, 9, 2021976];
+
+        assert_eq!(blink(input), Some(output));
+    }
+
+    #[test]
+    fn test_blink_nth() {
+        let output = vec![253, 0, 2024, 14168];
+
+        assert_eq!(blink_nth(example(), 2), Some(output));
+    }
+
+    #[test]
+    fn test_count_stones() {
+        let stones = example();
+        let mut cache = Cache::new();
+
+        for times in [0, 1, 6, 25] {
+            assert_eq!(
+                count_stones(&stones, times, &mut cache),
+                blink_nth(stones.clone(), times).map(|stones| stones.len() as u128)
+            );
+        }
+
+        cache.clear();
+        count_stones(&stones, 75, &mut cache).unwrap();
+        // Only a few thousand distinct stones ever appear, each computed once.
+        assert!(cache.len() < 5000, "{}", cache.stats());
+        assert_eq!(cache.stats().misses, cache.len() as u64);
+    }
+
+    #[test]
+    fn test_count_many_blinks() {
+        let stones = example();
+        let mut cache = Cache::new();
+
+        // 150 blinks are 125 blinks of each of the stones left after 25 ones.
+        let blinked = blink_nth(stones.clone(), 25).unwrap();
+        assert_eq!(
+            count_stones(&stones, 150, &mut cache),
+            count_stones(&blinked, 125, &mut cache)
+        );
+
+        // The count outgrows a `u128` after about 200 blinks.
+        assert!(count_stones(&stones, 190, &mut cache).is_some());
+        assert_eq!(count_stones(&stones, 300, &mut cache), None);
+    }
+
+    #[test]
+    fn test_large_stone() {
+        // A stone of 19 digits is multiplied by 2024, which overflows.
+        let stone = 9_999_999_999_999_999_999;
+
+        assert_eq!(blink(vec![stone]), None);
+        assert_eq!(count_stones(&[stone], 1, &mut Cache::new()), None);
+        assert_eq!(
+            Day11::part2(&vec![stone]).unwrap_err().to_string(),
+            "no answer: The stones of 75 blinks are too large to count"
+        );
+    }
+
+    aoc::readme_tests!(Day11);
+}
@@ -0,0 +1,620 @@
+use aoc::{Error, Solution};
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub struct Day01;
+
+aoc::register!(Day01);
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rotation>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_rotations(input, Strictness::Strict).map_err(|errors| malformed_rotations(&errors))
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<i64, Error> {
+        let mut safe = Safe::new(50, 99);
+        safe.unlock(rotations.iter().copied());
+
+        Ok(safe.dial_stopped_at_zero())
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<i64, Error> {
+        let mut safe = Safe::new(50, 99);
+        safe.unlock(rotations.iter().copied());
+
+        Ok(safe.times_passed_through_zero())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Left(i64),
+    Right(i64),
+}
+
+/// How strictly rotations are read.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// An uppercase direction followed by the distance, e.g. `L68`.
+    #[default]
+    Strict,
+    /// Lowercase directions and whitespace around the direction and the distance are accepted
+    /// too, e.g. ` l 68`.
+    Lenient,
+}
+
+/// Why a line is not a rotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    Empty,
+    Direction(char),
+    Distance(ParseIntError),
+}
+
+/// A line which is not a rotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationError {
+    /// The number of the line, starting at 1.
+    pub line: usize,
+    pub text: String,
+    pub reason: Reason,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reason::Empty => write!(f, "Missing rotation"),
+            Reason::Direction(c) => write!(f, "Unknown direction {c:?}, expected `L` or `R`"),
+            Reason::Distance(e) => write!(f, "Distance is not a number: {e}"),
+        }
+    }
+}
+
+impl Display for RotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {} in {:?}", self.line, self.reason, self.text)
+    }
+}
+
+impl std::error::Error for RotationError {}
+
+impl Rotation {
+    /// Reads a rotation, e.g. `L68`. Errors point at line 1.
+    pub fn parse(text: &str, strictness: Strictness) -> Result<Self, RotationError> {
+        let error = |reason| RotationError {
+            line: 1,
+            text: text.to_string(),
+            reason,
+        };
+        let trimmed = match strictness {
+            Strictness::Strict => text,
+            Strictness::Lenient => text.trim(),
+        };
+
+        let mut chars = trimmed.chars();
+        let direction = chars.next().ok_or_else(|| error(Reason::Empty))?;
+        let distance = match strictness {
+            Strictness::Strict => chars.as_str(),
+            Strictness::Lenient => chars.as_str().trim_start(),
+        };
+
+        let rotation = match (direction, strictness) {
+            ('L', _) | ('l', Strictness::Lenient) => Self::Left,
+            ('R', _) | ('r', Strictness::Lenient) => Self::Right,
+            (c, _) => return Err(error(Reason::Direction(c))),
+        };
+
+        distance
+            .parse::<i64>()
+            .map(rotation)
+            .map_err(|e| error(Reason::Distance(e)))
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = RotationError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text, Strictness::Strict)
+    }
+}
+
+impl TryFrom<&str> for Rotation {
+    type Error = RotationError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+/// Builds the parse error listing every malformed line, pointing at the first one.
+pub fn malformed_rotations(errors: &[RotationError]) -> Error {
+    let lines: Vec<String> = errors.iter().map(RotationError::to_string).collect();
+
+    Error::parse(
+        errors.first().map_or(1, |error| error.line),
+        1,
+        format!("Malformed rotations\n{}", lines.join("\n")),
+    )
+}
+
+/// Reads a rotation per line, skipping the empty ones. Every malformed line is reported.
+pub fn parse_rotations(
+    input: &str,
+    strictness: Strictness,
+) -> Result<Vec<Rotation>, Vec<RotationError>> {
+    let mut rotations = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        match Rotation::parse(line, strictness) {
+            Ok(rotation) => rotations.push(rotation),
+            Err(error) => errors.push(RotationError {
+                line: index + 1,
+                ..error
+            }),
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(rotations),
+        false => Err(errors),
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rotation::Left(distance) => write!(f, "L{distance}"),
+            Rotation::Right(distance) => write!(f, "R{distance}"),
+        }
+    }
+}
+
+/// What a rotation did to the dial of a [`Safe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub rotation: Rotation,
+    /// The pin the dial pointed at before the rotation.
+    pub start: i64,
+    /// The pin the dial points at after the rotation.
+    pub end: i64,
+    /// How many clicks of the rotation landed on zero, the last one included.
+    pub zeros: i64,
+    pub stopped_at_zero: bool,
+}
+
+/// A safe whose dial shows the pins from 0 to `pins_size`, and counts how often it points at 0.
+pub struct Safe {
+    dial: i64,
+    dial_stopped_at_zero: i64,
+    times_passed_through_zero: i64,
+    /// The number of pins on the dial.
+    size: i64,
+    /// The rotations applied so far, in order.
+    history: Vec<Turn>,
+    /// The rotations undone, the last one undone at the end.
+    undone: Vec<Rotation>,
+}
+
+impl Safe {
+    /// # Panics
+    ///
+    /// Panics if `pins_size` is negative, or too large for the number of pins to fit in an `i64`.
+    pub fn new(dial_start: i64, pins_size: i64) -> Self {
+        assert!(pins_size >= 0, "The dial needs at least one pin");
+        let size = pins_size.checked_add(1).expect("Too many pins");
+
+        Self {
+            dial: dial_start.rem_euclid(size),
+            dial_stopped_at_zero: 0,
+            times_passed_through_zero: 0,
+            size,
+            history: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    pub fn unlock(&mut self, rotations: impl IntoIterator<Item = Rotation>) {
+        for rotation in rotations {
+            self.turn(rotation);
+        }
+    }
+
+    /// Turns the dial towards lower pins. A negative distance turns it the other way.
+    pub fn left(&mut self, distance: i64) {
+        self.turn(Rotation::Left(distance));
+    }
+
+    /// Turns the dial towards higher pins. A negative distance turns it the other way.
+    pub fn right(&mut self, distance: i64) {
+        self.turn(Rotation::Right(distance));
+    }
+
+    /// Applies a rotation and records it in the history. The rotations undone so far can no
+    /// longer be redone.
+    pub fn turn(&mut self, rotation: Rotation) -> Turn {
+        self.undone.clear();
+
+        self.apply(rotation)
+    }
+
+    /// The rotations applied so far, with what they did to the dial.
+    pub fn history(&self) -> &[Turn] {
+        &self.history
+    }
+
+    /// Cancels the last rotation, restoring the dial and the counters as they were before it.
+    /// Returns the rotation undone, if any.
+    pub fn undo(&mut self) -> Option<Turn> {
+        let turn = self.history.pop()?;
+
+        self.dial = turn.start;
+        self.times_passed_through_zero -= turn.zeros;
+        self.dial_stopped_at_zero -= i64::from(turn.stopped_at_zero);
+        self.undone.push(turn.rotation);
+
+        Some(turn)
+    }
+
+    /// Applies again the last rotation undone, if any.
+    pub fn redo(&mut self) -> Option<Turn> {
+        let rotation = self.undone.pop()?;
+
+        Some(self.apply(rotation))
+    }
+
+    /// Undoes or redoes rotations until the given number of them are applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer rotations were applied and undone.
+    pub fn seek(&mut self, step: usize) {
+        assert!(
+            step <= self.history.len() + self.undone.len(),
+            "Only {} rotations to replay, not {step}",
+            self.history.len() + self.undone.len()
+        );
+
+        while self.history.len() > step {
+            self.undo();
+        }
+        while self.history.len() < step {
+            self.redo();
+        }
+    }
+
+    pub fn dial(&self) -> i64 {
+        self.dial
+    }
+
+    pub fn dial_stopped_at_zero(&self) -> i64 {
+        self.dial_stopped_at_zero
+    }
+
+    pub fn times_passed_through_zero(&self) -> i64 {
+        self.times_passed_through_zero
+    }
+
+    /// Applies a rotation in constant time, and records it in the history.
+    fn apply(&mut self, rotation: Rotation) -> Turn {
+        let start = self.dial;
+        let (zeros, stopped) = (self.times_passed_through_zero, self.dial_stopped_at_zero);
+
+        match rotation {
+            Rotation::Left(distance) => self.rotate(-i128::from(distance)),
+            Rotation::Right(distance) => self.rotate(i128::from(distance)),
+        }
+
+        let turn = Turn {
+            rotation,
+            start,
+            end: self.dial,
+            zeros: self.times_passed_through_zero - zeros,
+            stopped_at_zero: self.dial_stopped_at_zero > stopped,
+        };
+        self.history.push(turn);
+
+        turn
+    }
+
+    /// Turns the dial by a number of clicks, to the right when positive.
+    fn rotate(&mut self, clicks: i128) {
+        let size = i128::from(self.size);
+        let dial = i128::from(self.dial);
+
+        // Turning left from a pin passes through zero as often as turning right from its mirror
+        // image, e.g. turning left from 30 is turning right from 70 on a 100 pins dial.
+        let (from, distance) = match clicks >= 0 {
+            true => (dial, clicks),
+            false => ((size - dial) % size, -clicks),
+        };
+
+        // Each click landing on zero counts, the last one included.
+        let passed = (from + distance) / size;
+        self.times_passed_through_zero += i64::try_from(passed).expect("Too many turns");
+
+        self.dial = (dial + clicks).rem_euclid(size) as i64;
+
+        if self.dial == 0 {
+            self.dial_stopped_at_zero += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let mut safe = Safe::new(50, 99);
+
+        let input = aoc::examples::example(include_str!("../README.md"), "example1");
+        let rotations = Day01::parse(&input).unwrap();
+
+        safe.unlock(rotations);
+
+        assert_eq!(safe.dial_stopped_at_zero(), 3);
+        assert_eq!(safe.times_passed_through_zero(), 6);
+    }
+
+    #[test]
+    fn test_left_right() {
+        let mut safe = Safe::new(50, 99);
+
+        safe.right(1);
+        assert_eq!(safe.dial(), 51);
+
+        safe.right(1);
+        assert_eq!(safe.dial(), 52);
+
+        safe.left(10);
+        assert_eq!(safe.dial(), 42);
+
+        safe.left(43);
+        assert_eq!(safe.dial(), 99);
+
+        safe.right(99);
+        assert_eq!(safe.dial(), 98);
+
+        safe.right(100);
+        assert_eq!(safe.dial(), 98);
+
+        safe.left(1000);
+        assert_eq!(safe.dial(), 98);
+    }
+
+    #[test]
+    fn test_parse_rotation() {
+        assert_eq!("L68".parse(), Ok(Rotation::Left(68)));
+        assert_eq!(Rotation::try_from("R0"), Ok(Rotation::Right(0)));
+        assert_eq!("R-5".parse(), Ok(Rotation::Right(-5)));
+
+        let reason = |text: &str| text.parse::<Rotation>().unwrap_err().reason;
+        assert_eq!(reason(""), Reason::Empty);
+        assert_eq!(reason("l68"), Reason::Direction('l'));
+        assert_eq!(reason("éL"), Reason::Direction('é'));
+        assert!(matches!(reason("L"), Reason::Distance(_)));
+        assert!(matches!(reason("L 68"), Reason::Distance(_)));
+        assert!(matches!(reason("R12x"), Reason::Distance(_)));
+
+        let error = "X1".parse::<Rotation>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"line 1: Unknown direction 'X', expected `L` or `R` in "X1""#
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let lenient = |text| Rotation::parse(text, Strictness::Lenient);
+
+        assert_eq!(lenient(" l 68 "), Ok(Rotation::Left(68)));
+        assert_eq!(lenient("r12\t"), Ok(Rotation::Right(12)));
+        assert_eq!(lenient("R 1"), Ok(Rotation::Right(1)));
+        assert_eq!(lenient("  ").unwrap_err().reason, Reason::Empty);
+        assert_eq!(lenient("x1").unwrap_err().reason, Reason::Direction('x'));
+    }
+
+    #[test]
+    fn test_parse_rotations() {
+        let input = "L68\nl30\n\nR48\nL\nR 5\n";
+
+        let errors = parse_rotations(input, Strictness::Strict).unwrap_err();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.line, error.text.as_str()))
+                .collect::<Vec<_>>(),
+            [(2, "l30"), (5, "L"), (6, "R 5")]
+        );
+
+        let errors = parse_rotations(input, Strictness::Lenient).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 5);
+
+        assert_eq!(
+            parse_rotations("L68\n\nR48\n", Strictness::Strict),
+            Ok(vec![Rotation::Left(68), Rotation::Right(48)])
+        );
+        match Day01::parse(input) {
+            Err(Error::Parse { line, message, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(
+                    message.lines().collect::<Vec<_>>(),
+                    [
+                        "Malformed rotations",
+                        r#"line 2: Unknown direction 'l', expected `L` or `R` in "l30""#,
+                        r#"line 5: Distance is not a number: cannot parse integer from empty string in "L""#,
+                        r#"line 6: Distance is not a number: invalid digit found in string in "R 5""#,
+                    ]
+                );
+            }
+            result => panic!("Unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_history() {
+        let mut safe = Safe::new(50, 99);
+        safe.unlock([
+            Rotation::Left(68),
+            Rotation::Left(30),
+            Rotation::Right(48),
+            Rotation::Left(250),
+        ]);
+
+        assert_eq!(
+            safe.history()[0],
+            Turn {
+                rotation: Rotation::Left(68),
+                start: 50,
+                end: 82,
+                zeros: 1,
+                stopped_at_zero: false,
+            }
+        );
+        assert_eq!(
+            safe.history()
+                .iter()
+                .map(|turn| turn.end)
+                .collect::<Vec<_>>(),
+            [82, 52, 0, 50]
+        );
+        assert_eq!(
+            safe.history()
+                .iter()
+                .map(|turn| turn.zeros)
+                .collect::<Vec<_>>(),
+            [1, 0, 1, 2]
+        );
+        assert_eq!(safe.history()[2].rotation.to_string(), "R48");
+        assert!(safe.history()[2].stopped_at_zero);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let rotations = [
+            Rotation::Left(68),
+            Rotation::Left(30),
+            Rotation::Right(48),
+            Rotation::Left(5),
+            Rotation::Right(60),
+        ];
+        let mut safe = Safe::new(50, 99);
+        safe.unlock(rotations);
+        let state = |safe: &Safe| {
+            (
+                safe.dial(),
+                safe.dial_stopped_at_zero(),
+                safe.times_passed_through_zero(),
+            )
+        };
+
+        // Each step is the state of a safe given the first rotations only.
+        for step in [3, 0, 5, 1, 4] {
+            safe.seek(step);
+
+            let mut replayed = Safe::new(50, 99);
+            replayed.unlock(rotations[..step].iter().copied());
+            assert_eq!(state(&safe), state(&replayed), "step {step}");
+            assert_eq!(safe.history(), replayed.history());
+        }
+
+        safe.seek(2);
+        assert_eq!(
+            safe.undo().map(|turn| turn.rotation),
+            Some(Rotation::Left(30))
+        );
+        assert_eq!(
+            safe.redo().map(|turn| turn.rotation),
+            Some(Rotation::Left(30))
+        );
+
+        // A new rotation forgets the ones undone.
+        safe.right(1);
+        assert_eq!(safe.redo(), None);
+        assert_eq!(safe.history().len(), 3);
+
+        safe.seek(0);
+        assert_eq!(safe.undo(), None);
+        assert_eq!(state(&safe), (50, 0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only 2 rotations to replay, not 3")]
+    fn test_seek_too_far() {
+        let mut safe = Safe::new(50, 99);
+        safe.unlock([Rotation::Left(1), Rotation::Right(1)]);
+
+        safe.seek(3);
+    }
+
+    /// Turns the dial one click at a time, returning the dial and how often it landed on zero.
+    fn rotate_brute_force(size: i64, dial: i64, clicks: i64) -> (i64, i64) {
+        let (mut dial, mut zeros) = (dial, 0);
+
+        for _ in 0..clicks.abs() {
+            dial = (dial + clicks.signum()).rem_euclid(size);
+            zeros += i64::from(dial == 0);
+        }
+
+        (dial, zeros)
+    }
+
+    #[test]
+    fn test_brute_force() {
+        for size in [1, 2, 3, 7, 100] {
+            for start in 0..size {
+                for clicks in -250..=250 {
+                    let mut safe = Safe::new(start, size - 1);
+                    safe.right(clicks);
+
+                    let (dial, zeros) = rotate_brute_force(size, start, clicks);
+                    assert_eq!(safe.dial(), dial, "{size} pins, {start} {clicks:+}");
+                    assert_eq!(safe.times_passed_through_zero(), zeros);
+                    assert_eq!(safe.dial_stopped_at_zero(), i64::from(dial == 0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_rotations() {
+        let mut safe = Safe::new(50, 99);
+
+        safe.left(i64::MAX);
+        assert_eq!(safe.dial(), (50 - i64::MAX).rem_euclid(100));
+        assert_eq!(safe.times_passed_through_zero(), (i64::MAX - 50) / 100 + 1);
+
+        // Negative distances turn the other way, the smallest one included.
+        let dial = safe.dial();
+        safe.right(i64::MIN);
+        safe.left(i64::MIN);
+        assert_eq!(safe.dial(), dial);
+        safe.left(-7);
+        assert_eq!(safe.dial(), (dial + 7) % 100);
+
+        let mut huge = Safe::new(3, i64::MAX - 1);
+        huge.right(i64::MAX - 3);
+        assert_eq!((huge.dial(), huge.times_passed_through_zero()), (0, 1));
+        huge.left(1);
+        assert_eq!(huge.dial(), i64::MAX - 1);
+        assert_eq!(huge.dial_stopped_at_zero(), 1);
+    }
+
+    aoc::readme_tests!(Day01);
+}
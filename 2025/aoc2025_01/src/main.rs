@@ -1,196 +1,94 @@
-#[derive(Debug, Clone)]
-enum Rotation {
-    Left(i32),
-    Right(i32),
+use aoc::{Error, Solution};
+use aoc2025_01::{Day01, Rotation, Safe, Strictness, Turn, malformed_rotations, parse_rotations};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(flatten)]
+    args: aoc::Args,
+
+    /// Print what each rotation did to the dial, instead of solving.
+    #[arg(long)]
+    history: bool,
+
+    /// Only apply the given number of rotations to the printed history.
+    #[arg(long, requires = "history")]
+    step: Option<usize>,
+
+    /// Accept lowercase directions and whitespace around the direction and
+    /// the distance of the rotations.
+    #[arg(long)]
+    lenient: bool,
 }
 
-impl From<String> for Rotation {
-    fn from(value: String) -> Self {
-        let (direction, distance) = value.split_at(1);
-
-        match (direction, distance) {
-            ("L", distance) => Self::Left(
-                distance
-                    .parse::<i32>()
-                    .expect("Left input distance is invalid!"),
-            ),
-            ("R", distance) => Self::Right(
-                distance
-                    .parse::<i32>()
-                    .expect("Right input distance is invalid!"),
-            ),
-            (_, _) => panic!("Invalid input!"),
-        }
-    }
-}
-
-struct Safe {
-    dial: i32,
-    dial_stopped_at_zero: i32,
-    times_passed_through_zero: i32,
-    pins: Vec<i32>,
-}
-
-impl Safe {
-    pub fn new(dial_start: i32, pins_size: i32) -> Self {
-        let pins: Vec<i32> = (0..=pins_size).collect();
-
-        Self {
-            dial: dial_start,
-            dial_stopped_at_zero: 0,
-            times_passed_through_zero: 0,
-            pins,
-        }
-    }
-
-    pub fn unlock(&mut self, rotations: Vec<Rotation>) {
-        rotations.iter().for_each(|rotation| match rotation {
-            Rotation::Left(distance) => self.left(*distance),
-            Rotation::Right(distance) => self.right(*distance),
-        });
-    }
-
-    pub fn left(&mut self, distance: i32) {
-        let mut iter = self.pins.iter().copied().rev().cycle();
-        let len = self.pins.len();
-        let dial = &mut self.dial;
-        let times = &mut self.times_passed_through_zero;
-        let stopped = &mut self.dial_stopped_at_zero;
-
-        rotate(distance, &mut iter, dial, len, times, stopped);
-    }
-
-    pub fn right(&mut self, distance: i32) {
-        let mut iter = self.pins.iter().copied().cycle();
-
-        let len = self.pins.len();
-        let dial = &mut self.dial;
-        let times = &mut self.times_passed_through_zero;
-        let stopped = &mut self.dial_stopped_at_zero;
-
-        rotate(distance, &mut iter, dial, len, times, stopped);
-    }
-
-    pub fn dial(&self) -> i32 {
-        self.dial
-    }
-
-    pub fn dial_stopped_at_zero(&self) -> i32 {
-        self.dial_stopped_at_zero
-    }
+fn main() {
+    let cli = Cli::parse();
 
-    pub fn times_passed_through_zero(&self) -> i32 {
-        self.times_passed_through_zero
+    if let Err(error) = run(&cli) {
+        aoc::exit_with(&error);
     }
 }
 
-fn rotate<I>(
-    distance: i32,
-    iter: &mut I,
-    dial: &mut i32,
-    len: usize,
-    times_passed_through_zero: &mut i32,
-    dial_stopped_at_zero: &mut i32,
-) where
-    I: Iterator<Item = i32>,
-{
-    // Move the iterator to the dial position
-    for _ in 0..len {
-        if let Some(pin) = iter.next()
-            && pin == *dial
-        {
-            break;
-        }
+fn run(cli: &Cli) -> Result<(), Error> {
+    let input = cli.args.load(Day01::YEAR, Day01::DAY)?;
+    let strictness = match cli.lenient {
+        true => Strictness::Lenient,
+        false => Strictness::Strict,
+    };
+
+    let rotations =
+        parse_rotations(&input, strictness).map_err(|errors| malformed_rotations(&errors))?;
+
+    match cli.history {
+        true => history(cli, &rotations),
+        // The rotations are solved as read, whatever their strictness.
+        false => aoc::print_records(
+            &aoc::solve_parsed::<Day01>(&cli.args, &rotations),
+            cli.args.format,
+        )?,
     }
 
-    // Then move for the given distance
-    (0..distance).for_each(|step| {
-        if let Some(pin) = iter.next() {
-            // we are at zero AND there are steps remaining
-            if pin == 0 && step != distance {
-                *times_passed_through_zero += 1;
-            }
-
-            *dial = pin;
-        }
-    });
-
-    // Check if the dial is at zero
-    if *dial == 0 {
-        *dial_stopped_at_zero += 1;
-    }
+    Ok(())
 }
 
-fn main() {
-    let lines = aoc::init();
-    let rotations: Vec<Rotation> = lines
-        .into_iter()
-        .filter(|l| !l.is_empty())
-        .map(Rotation::from)
-        .collect();
+fn history(cli: &Cli, rotations: &[Rotation]) {
     let mut safe = Safe::new(50, 99);
+    safe.unlock(rotations.iter().copied());
+    safe.seek(cli.step.unwrap_or(rotations.len()).min(rotations.len()));
 
-    safe.unlock(rotations);
-
-    let final_dial = safe.dial();
-    let zero_passes = safe.dial_stopped_at_zero();
-    let total_pass_zero = safe.times_passed_through_zero();
+    print_turns(safe.history());
 
     println!(
-        "Final dial: {final_dial}. Finished at zero: {zero_passes} times. Passed by zero {total_pass_zero} times"
+        "\nDial at {}, stopped {} times at zero, passed {} times through zero",
+        safe.dial(),
+        safe.dial_stopped_at_zero(),
+        safe.times_passed_through_zero()
     );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example() {
-        let mut safe = Safe::new(50, 99);
-
-        let rotations = vec![
-            Rotation::Left(68),
-            Rotation::Left(30),
-            Rotation::Right(48),
-            Rotation::Left(5),
-            Rotation::Right(60),
-            Rotation::Left(55),
-            Rotation::Left(1),
-            Rotation::Left(99),
-            Rotation::Right(14),
-            Rotation::Left(82),
-        ];
-
-        safe.unlock(rotations);
-
-        assert_eq!(safe.dial_stopped_at_zero(), 3);
-        assert_eq!(safe.times_passed_through_zero(), 6);
-    }
-
-    #[test]
-    fn test_left_right() {
-        let mut safe = Safe::new(50, 99);
-
-        safe.right(1);
-        assert_eq!(safe.dial(), 51);
-
-        safe.right(1);
-        assert_eq!(safe.dial(), 52);
-
-        safe.left(10);
-        assert_eq!(safe.dial(), 42);
+fn print_turns(turns: &[Turn]) {
+    let rotation = turns
+        .iter()
+        .map(|turn| turn.rotation.to_string().len())
+        .chain(["ROTATION".len()])
+        .max()
+        .unwrap();
 
-        safe.left(43);
-        assert_eq!(safe.dial(), 99);
-
-        safe.right(99);
-        assert_eq!(safe.dial(), 98);
-
-        safe.right(100);
-        assert_eq!(safe.dial(), 98);
+    println!(
+        "STEP  {:rotation$}  START    END  ZEROS  STOPPED",
+        "ROTATION"
+    );
 
-        safe.left(1000);
-        assert_eq!(safe.dial(), 98);
+    for (index, turn) in turns.iter().enumerate() {
+        println!(
+            "{:>4}  {:rotation$}  {:>5}  {:>5}  {:>5}  {}",
+            index + 1,
+            turn.rotation.to_string(),
+            turn.start,
+            turn.end,
+            turn.zeros,
+            if turn.stopped_at_zero { "yes" } else { "no" },
+        );
     }
 }
@@ -0,0 +1,491 @@
+use aoc::{Error, Solution};
+
+/// A range of IDs, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Range {
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+}
+
+pub struct Day02;
+
+aoc::register!(Day02);
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Range>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| Error::parse(1, 1, "Missing ID ranges"))?;
+
+        line.split(",")
+            .map(|chunk| {
+                let (start, end) = chunk
+                    .split_once("-")
+                    .ok_or_else(|| Error::at(1, line, chunk, "Failed to parse a chunk"))?;
+                let start = start.parse::<u64>().map_err(|e| {
+                    Error::at(1, line, start, format!("Start is not a number: {e}"))
+                })?;
+                let end = end
+                    .parse::<u64>()
+                    .map_err(|e| Error::at(1, line, end, format!("End is not a number: {e}")))?;
+
+                Ok(Range::new(start, end))
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<u128, Error> {
+        Ok(solve(ranges).0)
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<u128, Error> {
+        Ok(solve(ranges).1)
+    }
+}
+
+// take the ranges and reduce them to return the sum of all the invalid ids
+fn solve(ranges: &[Range]) -> (u128, u128) {
+    ranges
+        .iter()
+        .map(invalid_ids_in_range)
+        .fold((0, 0), |acc, e| (acc.0 + e.0, acc.1 + e.1))
+}
+
+// Takes a range a return a tuple containing the sum of ids repeating exactly twice, and the sum
+// of ids repeating at least twice.
+fn invalid_ids_in_range(range: &Range) -> (u128, u128) {
+    (
+        Query::new(Repetition::Exactly(2), 10).sum(range),
+        Query::new(Repetition::AtLeast(2), 10).sum(range),
+    )
+}
+
+/// How many times the block of digits making an id is repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repetition {
+    Exactly(u32),
+    AtLeast(u32),
+}
+
+/// Selects the ids written, in a given base, as a block of digits repeated, e.g. 123123 is 123
+/// repeated exactly twice and 111111 is 1 repeated at least 3 times. As ids have no leading
+/// zeros, neither do the blocks, and 0 is never selected.
+///
+/// The ids are not searched for among the ids of a range: they are the blocks, repeated, falling
+/// in the range, whose sums and counts are computed for each length of ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Query {
+    pub repetition: Repetition,
+    pub base: u32,
+}
+
+/// The number and sum of some ids.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Total {
+    count: u128,
+    sum: u128,
+}
+
+impl Query {
+    /// # Panics
+    ///
+    /// Panics if the base is less than 2.
+    pub fn new(repetition: Repetition, base: u32) -> Self {
+        assert!(base >= 2, "The base must be at least 2, not {base}");
+
+        Self { repetition, base }
+    }
+
+    /// Returns the sum of the selected ids of the range.
+    pub fn sum(&self, range: &Range) -> u128 {
+        self.total(range).sum
+    }
+
+    /// Returns the number of selected ids of the range.
+    pub fn count(&self, range: &Range) -> u128 {
+        self.total(range).count
+    }
+
+    /// Returns the selected ids of the range, in increasing order.
+    pub fn list(&self, range: &Range) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .lengths(range)
+            .flat_map(|digits| {
+                self.periods(digits).into_iter().flat_map(move |period| {
+                    let (factor, blocks) = self.blocks(range, digits, period);
+                    blocks.map(move |block| (block * factor) as u64)
+                })
+            })
+            .collect();
+
+        // Ids repeating several blocks, e.g. 111111, are found once for each.
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids
+    }
+
+    fn total(&self, range: &Range) -> Total {
+        let mut total = Total::default();
+
+        for digits in self.lengths(range) {
+            for period in self.periods(digits) {
+                let ids = match self.repetition {
+                    // A single block length repeats exactly the given number of times.
+                    Repetition::Exactly(_) => self.repeating(range, digits, period),
+                    // An id repeating a block also repeats the longer blocks made of it, e.g.
+                    // 111111 is both 111 and 11 repeated: each id is only counted for its
+                    // shortest block.
+                    Repetition::AtLeast(_) => self.shortest_repeating(range, digits, period),
+                };
+
+                total.count += ids.count;
+                total.sum += ids.sum;
+            }
+        }
+
+        total
+    }
+
+    /// Returns the numbers of digits of the ids of the range.
+    fn lengths(&self, range: &Range) -> std::ops::RangeInclusive<u32> {
+        let digits = |id: u64| id.checked_ilog(u64::from(self.base)).unwrap_or(0) + 1;
+
+        digits(range.start)..=digits(range.end)
+    }
+
+    /// Returns the lengths of the blocks repeated the requested number of times by ids of the
+    /// given number of digits. With [`Repetition::AtLeast`], they include their own divisors.
+    fn periods(&self, digits: u32) -> Vec<u32> {
+        (1..=digits)
+            .filter(|&period| digits.is_multiple_of(period))
+            .filter(|&period| match self.repetition {
+                Repetition::Exactly(times) => digits / period == times,
+                Repetition::AtLeast(times) => digits / period >= times,
+            })
+            .collect()
+    }
+
+    /// Returns the factor turning a block of `period` digits into an id of `digits` digits
+    /// repeating it, and the blocks whose ids fall in the range. `period` must divide `digits`.
+    fn blocks(
+        &self,
+        range: &Range,
+        digits: u32,
+        period: u32,
+    ) -> (u128, std::ops::RangeInclusive<u128>) {
+        let base = u128::from(self.base);
+
+        // Such an id is its block times a number made of ones spaced by zeros, e.g.
+        // 123123 = 123 × 1001 or 121212 = 12 × 10101 in base 10.
+        let factor = (base.pow(digits) - 1) / (base.pow(period) - 1);
+
+        let first = base
+            .pow(period - 1)
+            .max(u128::from(range.start).div_ceil(factor));
+        let last = (base.pow(period) - 1).min(u128::from(range.end) / factor);
+
+        (factor, first..=last)
+    }
+
+    /// Returns the ids of the range with the given number of digits, made of a block of `period`
+    /// digits repeated.
+    fn repeating(&self, range: &Range, digits: u32, period: u32) -> Total {
+        let (factor, blocks) = self.blocks(range, digits, period);
+        let (first, last) = blocks.into_inner();
+
+        if first > last {
+            return Total::default();
+        }
+
+        let count = last - first + 1;
+
+        Total {
+            count,
+            // The sum of the blocks from `first` to `last`, repeated.
+            sum: factor * (first + last) * count / 2,
+        }
+    }
+
+    /// Returns the ids of the range with the given number of digits whose shortest repeated
+    /// block has `period` digits.
+    fn shortest_repeating(&self, range: &Range, digits: u32, period: u32) -> Total {
+        let mut total = self.repeating(range, digits, period);
+
+        // The ids repeating a shorter block repeat one whose length divides `period`.
+        for shorter in (1..period).filter(|&shorter| period.is_multiple_of(shorter)) {
+            let ids = self.shortest_repeating(range, digits, shorter);
+            total.count -= ids.count;
+            total.sum -= ids.sum;
+        }
+
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repeat_at_least_twice(id: u64) -> bool {
+        let str = id.to_string();
+
+        if str.len() <= 1 {
+            return false;
+        }
+
+        // If the number is of pair length, we can check at most half its size by chunk.
+        // If its not pair, at most half its size - 1.
+        let max_chunk_size = match str.len().is_multiple_of(2) {
+            true => str.len() / 2,
+            false => (str.len() - 1) / 2,
+        };
+
+        let chunk_sizes_to_check: Vec<usize> = (1..=max_chunk_size).collect();
+
+        for chunk_size in chunk_sizes_to_check {
+            // Split the str into parts of chunk_size length and check if they are all the same
+            let chunks: Vec<&str> = str
+                .as_bytes()
+                .chunks(chunk_size)
+                .map(|chunk| str::from_utf8(chunk).expect("Failed to parse chunk to utf8"))
+                .collect();
+
+            let first_chunk = chunks.first();
+            let all_equals = first_chunk.map(|first| chunks.iter().all(|c| c == first));
+
+            if let Some(true) = all_equals {
+                // This id repeats the first_chunk at least twice !
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Takes an ind and returns wether it is invalid (true) or not (false).
+    fn repeat_exactly_twice(id: u64) -> bool {
+        let str = id.to_string();
+
+        // Since we're looking for a sequence of digits repeated *twice*,
+        // we filter-out digits that does not have a pair length.
+        if !str.len().is_multiple_of(2) || str.is_empty() {
+            return false;
+        }
+
+        let parts = str.split_at(str.len() / 2);
+
+        // None of the numbers have leading zeros
+        if parts.0.starts_with("0") || parts.1.starts_with("0") {
+            return false;
+        }
+
+        parts.0.eq(parts.1)
+    }
+
+    /// Returns, for each id up to `last`, the sums of the invalid ids up to it found by checking
+    /// each id.
+    fn sums_brute_force(last: u64) -> Vec<(u128, u128)> {
+        (0..=last)
+            .scan((0, 0), |sums: &mut (u128, u128), id| {
+                let invalid = |invalid: bool| if invalid { u128::from(id) } else { 0 };
+                sums.0 += invalid(repeat_exactly_twice(id));
+                sums.1 += invalid(repeat_at_least_twice(id));
+                Some(*sums)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_id_repeat_at_least_twice() {
+        assert!(repeat_at_least_twice(111));
+        assert!(repeat_at_least_twice(999));
+        assert!(repeat_at_least_twice(565656));
+        assert!(repeat_at_least_twice(824824824));
+        assert!(repeat_at_least_twice(2121212121));
+
+        assert!(!repeat_at_least_twice(10123));
+    }
+
+    #[test]
+    fn test_id_repeat_twice() {
+        assert!(repeat_exactly_twice(11));
+        assert!(repeat_exactly_twice(1010));
+        assert!(repeat_exactly_twice(1188511885));
+        assert!(repeat_exactly_twice(222222));
+        assert!(repeat_exactly_twice(446446));
+
+        assert!(!repeat_exactly_twice(446445));
+        assert!(!repeat_exactly_twice(0));
+        assert!(!repeat_exactly_twice(1));
+        assert!(!repeat_exactly_twice(993));
+    }
+
+    #[test]
+    fn test_invalid_ids_in_range() {
+        let input = aoc::examples::example(include_str!("../README.md"), "example1");
+        let ranges = Day02::parse(&input).unwrap();
+
+        // The invalid ids of each range of the puzzle description.
+        let expected = [
+            (33, 33),
+            (99, 99 + 111),
+            (1010, 999 + 1010),
+            (1188511885, 1188511885),
+            (222222, 222222),
+            (0, 0),
+            (446446, 446446),
+            (38593859, 38593859),
+            (0, 565656),
+            (0, 824824824),
+            (0, 2121212121),
+        ];
+
+        assert_eq!(ranges.len(), expected.len());
+        for (range, expected) in ranges.iter().zip(expected) {
+            assert_eq!(invalid_ids_in_range(range), expected, "{range:?}");
+        }
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let sums = sums_brute_force(1_100_000);
+        let brute_force = |range: Range| match range.start {
+            0 => sums[range.end as usize],
+            start => {
+                let (end, before) = (sums[range.end as usize], sums[start as usize - 1]);
+                (end.0 - before.0, end.1 - before.1)
+            }
+        };
+
+        // Ranges of every length of ids, crossing from one length to the next.
+        let bounds = (0..1_100_000).step_by(4999).chain([
+            0, 9, 10, 99, 100, 1010, 99990, 100_010, 123_123, 999_999, 1_000_000,
+        ]);
+        let bounds: Vec<u64> = bounds.collect();
+
+        for &start in &bounds {
+            for &end in bounds.iter().filter(|&&end| end >= start) {
+                let range = Range::new(start, end);
+                assert_eq!(
+                    invalid_ids_in_range(&range),
+                    brute_force(range),
+                    "{range:?}"
+                );
+            }
+        }
+
+        assert_eq!(invalid_ids_in_range(&Range::new(10, 9)), (0, 0));
+    }
+
+    #[test]
+    fn test_large_range() {
+        // Billions of ids, with the largest ones.
+        let (twice, at_least_twice) = invalid_ids_in_range(&Range::new(1, u64::MAX));
+
+        assert!(twice > 0 && at_least_twice > twice);
+        assert_eq!(
+            invalid_ids_in_range(&Range::new(u64::MAX - 10, u64::MAX)),
+            (0, 0)
+        );
+    }
+
+    /// Returns whether the digits of the id in the base are a block repeated as requested.
+    fn is_repeated_brute_force(id: u64, query: &Query) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = id;
+        while rest > 0 {
+            digits.push(rest % u64::from(query.base));
+            rest /= u64::from(query.base);
+        }
+
+        (1..=digits.len() as u32)
+            .filter(|&times| match query.repetition {
+                Repetition::Exactly(k) => times == k,
+                Repetition::AtLeast(k) => times >= k,
+            })
+            .filter(|&times| digits.len().is_multiple_of(times as usize))
+            .any(|times| {
+                let period = digits.len() / times as usize;
+                digits
+                    .chunks(period)
+                    .all(|block| block == &digits[..period])
+            })
+    }
+
+    #[test]
+    fn test_query_brute_force() {
+        let ranges = [
+            Range::new(0, 3000),
+            Range::new(1, 1),
+            Range::new(7, 400),
+            Range::new(511, 1025),
+            Range::new(1000, 1000),
+        ];
+
+        for base in [2, 3, 7, 10, 16] {
+            for times in 0..=5 {
+                for repetition in [Repetition::Exactly(times), Repetition::AtLeast(times)] {
+                    let query = Query::new(repetition, base);
+
+                    for range in ranges {
+                        let ids: Vec<u64> = (range.start..=range.end)
+                            .filter(|&id| is_repeated_brute_force(id, &query))
+                            .collect();
+
+                        assert_eq!(query.list(&range), ids, "{query:?} {range:?}");
+                        assert_eq!(query.count(&range), ids.len() as u128);
+                        assert_eq!(
+                            query.sum(&range),
+                            ids.iter().map(|&id| u128::from(id)).sum::<u128>()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_query() {
+        let range = Range::new(100_000, 999_999);
+
+        // 111111 is a block repeated 2, 3 and 6 times.
+        let list = |repetition| Query::new(repetition, 10).list(&range);
+        assert!(list(Repetition::Exactly(2)).contains(&111111));
+        assert!(list(Repetition::Exactly(3)).contains(&111111));
+        assert!(list(Repetition::Exactly(6)).contains(&111111));
+        assert_eq!(list(Repetition::Exactly(6)).len(), 9);
+        assert!(list(Repetition::Exactly(4)).is_empty());
+
+        // 2 × 900 blocks of 3 digits or 90 of 2 digits, repeated, less the 9 counted twice.
+        let at_least_twice = Query::new(Repetition::AtLeast(2), 10);
+        assert_eq!(at_least_twice.count(&range), 900 + 90 - 9);
+
+        // 0b100100, 0b101101, 0b110110 and 0b111111 in binary.
+        let binary = Query::new(Repetition::Exactly(2), 2);
+        assert_eq!(binary.list(&Range::new(32, 63)), [36, 45, 54, 63]);
+        assert_eq!(binary.list(&Range::new(0, 3)), [3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The base must be at least 2")]
+    fn test_query_base() {
+        Query::new(Repetition::Exactly(2), 1);
+    }
+
+    aoc::readme_tests!(Day02);
+}
@@ -0,0 +1,177 @@
+use aoc::{Error, Solution};
+
+type Joltage = u64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Battery {
+    joltage: Joltage,
+}
+
+impl Battery {
+    pub fn new(joltage: Joltage) -> Self {
+        Self { joltage }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatteryBank {
+    batteries: Vec<Battery>,
+}
+
+impl BatteryBank {
+    pub fn new(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    /// Return the largest possible joltage for the bank by turning on
+    /// a given number of digits, or all of them when the bank has fewer.
+    ///
+    /// # Example
+    ///
+    /// - **98**7654321111111 returns 98 on two digits.
+    /// - **987654321111**111 returns 987654321111 on twelve digits.
+    ///
+    /// - **8**1111111111111**9** returns 89 on two digits.
+    /// - **81111111111**111**9** returns 89 on two digits.
+    ///
+    pub fn largest_possible_joltage_for_digits(&self, digits: usize) -> u64 {
+        let joltages: Vec<u64> = self.batteries.iter().map(|b| b.joltage).collect();
+
+        if digits == 0 || joltages.is_empty() {
+            return 0;
+        }
+
+        let mut to_remove = joltages.len().saturating_sub(digits);
+        let mut stack = Vec::new();
+
+        for joltage in joltages {
+            while to_remove > 0 {
+                match stack.last() {
+                    Some(&last) if last < joltage => {
+                        stack.pop();
+                        to_remove -= 1;
+                    }
+                    _ => break,
+                }
+            }
+            stack.push(joltage);
+        }
+
+        if to_remove > 0 {
+            let new_len = stack.len() - to_remove;
+            stack.truncate(new_len);
+        }
+
+        stack.truncate(digits);
+
+        stack.into_iter().fold(0, |acc, e| acc * 10 + e)
+    }
+}
+
+impl BatteryBank {
+    /// Reads the bank of the given line, one digit per battery.
+    pub fn parse(line_number: usize, line: &str) -> Result<Self, Error> {
+        let batteries = line
+            .char_indices()
+            .map(|(index, c)| {
+                let digit = c.to_digit(10).ok_or_else(|| {
+                    let token = &line[index..index + c.len_utf8()];
+                    Error::at(line_number, line, token, format!("Invalid joltage {c:?}"))
+                })?;
+
+                Ok(Battery::new(u64::from(digit)))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(BatteryBank::new(batteries))
+    }
+}
+
+pub struct Day03;
+
+aoc::register!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<BatteryBank>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| BatteryBank::parse(index + 1, line))
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Result<u64, Error> {
+        Ok(banks
+            .iter()
+            .fold(0, |acc, e| acc + e.largest_possible_joltage_for_digits(2)))
+    }
+
+    fn part2(banks: &Self::Input) -> Result<u64, Error> {
+        Ok(banks
+            .iter()
+            .fold(0, |acc, e| acc + e.largest_possible_joltage_for_digits(12)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_bank(line: &str) -> BatteryBank {
+        BatteryBank::parse(1, line).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let input = aoc::examples::example(include_str!("../README.md"), "example1");
+        let banks = Day03::parse(&input).unwrap();
+
+        let joltages = |digits| {
+            banks
+                .iter()
+                .map(|bank| bank.largest_possible_joltage_for_digits(digits))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(joltages(2), [98, 89, 78, 92]);
+        assert_eq!(
+            joltages(12),
+            [987654321111, 811111111119, 434234234278, 888911112111]
+        );
+    }
+
+    #[test]
+    fn test_short_bank() {
+        assert_eq!(parse_bank("81").largest_possible_joltage_for_digits(12), 81);
+        assert_eq!(parse_bank("").largest_possible_joltage_for_digits(2), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Day03::parse("987\n81x9"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day03::parse("9é7"),
+            Err(Error::Parse {
+                line: 1,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    aoc::readme_tests!(Day03);
+}
@@ -0,0 +1,152 @@
+use aoc::geom::Direction;
+use aoc::{Error, Grid, Solution};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Roll,
+    Space,
+}
+
+impl TryFrom<char> for Item {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Self::Roll),
+            '.' => Ok(Self::Space),
+            _ => Err(format!("Unexpected char {value:?}")),
+        }
+    }
+}
+
+impl From<&Item> for char {
+    fn from(val: &Item) -> Self {
+        match val {
+            Item::Roll => '@',
+            Item::Space => '.',
+        }
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+pub struct Day04;
+
+aoc::register!(Day04);
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid<Item>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.parse()
+    }
+
+    fn part1(matrix: &Self::Input) -> Result<usize, Error> {
+        Ok(count_accessible_rolls(4, matrix))
+    }
+
+    fn part2(matrix: &Self::Input) -> Result<usize, Error> {
+        Ok(count_removable_rolls(4, &mut matrix.clone()))
+    }
+}
+
+/// Returns the number of accessible rolls in a grid.
+///
+/// A roll is accessible when there are fewer than `access_threshold`
+/// rolls in the 8 surrounding cells.
+///
+/// For example, with the following grid (@ = roll, . is empty), and
+/// threshold of 4:
+///
+/// | _ | 0 | 1 | 2 | 3 |
+/// |---|---|---|---|---|
+/// | 0 | . | . | @ | @ |
+/// | 1 | @ | @ | @ | . |
+/// | 2 | @ | @ | @ | @ |
+/// | 3 | @ | . | @ | @ |
+///
+/// The accessible rolls are `[
+/// (1;1), (1;2),
+/// (2;0), (2;1), (2;2), (2;3)
+/// (3;1), (3;2), (3;3)
+/// ]`.
+///
+/// | _ | 0 | 1 | 2 | 3 |
+/// |---|---|---|---|---|
+/// | 0 | . | . | x | x |
+/// | 1 | x | @ | @ | . |
+/// | 2 | @ | @ | @ | @ |
+/// | 3 | x | . | @ | x |
+///
+/// Wich counts as 5 accessible rolls (marked by `x`).
+fn count_accessible_rolls(threshold: u16, matrix: &Grid<Item>) -> usize {
+    accessible_rolls(threshold, matrix).count()
+}
+
+/// Count the removable rolls.
+///
+/// Accessible rolls are removed, which may make other rolls accessible, until
+/// no roll is accessible anymore.
+fn count_removable_rolls(threshold: u16, matrix: &mut Grid<Item>) -> usize {
+    let mut removed = 0;
+
+    loop {
+        let rolls_to_remove: Vec<(usize, usize)> = accessible_rolls(threshold, matrix).collect();
+
+        if rolls_to_remove.is_empty() {
+            return removed;
+        }
+
+        removed += rolls_to_remove.len();
+        rolls_to_space(&rolls_to_remove, matrix);
+    }
+}
+
+/// Change the given rolls into space in the grid.
+fn rolls_to_space(rolls: &[(usize, usize)], matrix: &mut Grid<Item>) {
+    for &roll in rolls {
+        matrix[roll] = Item::Space;
+    }
+}
+
+/// Iterates over the positions of the accessible rolls.
+fn accessible_rolls(
+    threshold: u16,
+    matrix: &Grid<Item>,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    matrix
+        .cells()
+        .filter(move |&(position, item)| {
+            *item == Item::Roll && is_roll_accessible(position, threshold, matrix)
+        })
+        .map(|(position, _)| position)
+}
+
+/// Check wether the roll has fewer than `threshold` rolls in the 8
+/// surrounding cells.
+fn is_roll_accessible(position: (usize, usize), threshold: u16, matrix: &Grid<Item>) -> bool {
+    let rolls_in_range = Direction::ALL
+        .into_iter()
+        .filter_map(|direction| matrix.step(position, direction))
+        .filter(|&neighbour| matrix[neighbour] == Item::Roll)
+        .count();
+
+    rolls_in_range < threshold as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::readme_tests!(Day04);
+}
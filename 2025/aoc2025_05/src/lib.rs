@@ -0,0 +1,152 @@
+use aoc::ranges::RangeSet;
+use aoc::{Error, Solution};
+use std::ops::RangeInclusive;
+
+pub struct Day05;
+
+aoc::register!(Day05);
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = Recipe;
+    type Part1 = usize;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Number the lines from 1, to report errors at them
+        let lines: Vec<(usize, &str)> = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .collect();
+
+        // Split the ranges from the ingredients on the blank line
+        let mut separator = lines.split(|(_, line)| line.is_empty());
+        let ranges = separator.next().unwrap_or_default();
+        let ingredients = separator
+            .next()
+            .ok_or_else(|| Error::parse(ranges.len() + 1, 1, "Failed to get ingredients"))?;
+
+        // Overlapping ranges are merged once, for both parts.
+        let fresh = ranges
+            .iter()
+            .map(|&(line_number, line)| parse_range(line_number, line))
+            .collect::<Result<_, _>>()?;
+
+        let ingredients = ingredients
+            .iter()
+            .map(|&(line_number, line)| {
+                line.parse::<u64>().map_err(|e| {
+                    Error::parse(
+                        line_number,
+                        1,
+                        format!("Failed to parse ingredient id: {e}"),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Recipe { fresh, ingredients })
+    }
+
+    fn part1(recipe: &Self::Input) -> Result<usize, Error> {
+        Ok(recipe.count_fresh_ingredients_ids())
+    }
+
+    fn part2(recipe: &Self::Input) -> Result<u128, Error> {
+        Ok(recipe.count_fresh_ingredients_ids_from_ranges())
+    }
+}
+
+/// Parses an inclusive `start-end` range of fresh ingredient ids.
+fn parse_range(line_number: usize, line: &str) -> Result<RangeInclusive<u64>, Error> {
+    let (start, end) = line
+        .split_once("-")
+        .ok_or_else(|| Error::parse(line_number, 1, "Failed to get range end"))?;
+
+    let start = start.parse::<u64>().map_err(|e| {
+        Error::at(
+            line_number,
+            line,
+            start,
+            format!("Failed to parse range start: {e}"),
+        )
+    })?;
+    let end = end.parse::<u64>().map_err(|e| {
+        Error::at(
+            line_number,
+            line,
+            end,
+            format!("Failed to parse range end: {e}"),
+        )
+    })?;
+
+    Ok(start..=end)
+}
+
+pub struct Recipe {
+    /// The fresh ingredient ids, the overlapping ranges merged.
+    fresh: RangeSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+impl Recipe {
+    /// Counts the number of ingredients that are fresh (i.e that are in the ingredient list).
+    fn count_fresh_ingredients_ids(&self) -> usize {
+        self.ingredients
+            .iter()
+            .filter(|&&ingredient| self.fresh.contains(ingredient))
+            .count()
+    }
+
+    /// Count the fresh ingredients IDs only from the specified ranges, not
+    /// including the list of ingredients.
+    ///
+    /// Overlapping ranges are merged so that each id counts once.
+    fn count_fresh_ingredients_ids_from_ranges(&self) -> u128 {
+        self.fresh.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc::{Error, Solution};
+
+    #[test]
+    fn parse_test() {
+        let recipe = Day05::parse("3-5\n10-14\n\n1\n5").unwrap();
+
+        assert_eq!(recipe.fresh.spans().collect::<Vec<_>>(), [3..=5, 10..=14]);
+        assert_eq!(recipe.ingredients, vec![1, 5]);
+
+        assert!(matches!(
+            Day05::parse("3-5\n10-1x\n\n1\n5"),
+            Err(Error::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day05::parse("3-5\n10-14\n\n1\nfive"),
+            Err(Error::Parse { line: 5, .. })
+        ));
+        // Blank lines after the ingredients do not shift the line.
+        assert!(matches!(
+            Day05::parse("3-5\n\n1\nfive\n\n\n"),
+            Err(Error::Parse { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn count_wide_ranges_test() {
+        let recipe = Day05::parse("0-18446744073709551615\n5-10\n\n1").unwrap();
+
+        assert_eq!(Day05::part2(&recipe).unwrap(), 1 << 64);
+    }
+
+    aoc::readme_tests!(Day05);
+}
@@ -0,0 +1,92 @@
+//! Step-by-step renderings of grid simulations, played in the terminal.
+
+use crate::Grid;
+use crate::geom::Direction;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Clears the terminal and moves the cursor to its top-left corner.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// The marks drawn by [`mark`].
+const PATH: [char; 5] = ['|', '-', '/', '\\', '+'];
+
+/// Marks a move through a cell the way the puzzle descriptions draw paths:
+/// `|` for a vertical move, `-` for a horizontal one, `/` and `\` for the
+/// diagonal ones, and `+` where moves of different orientations cross.
+pub fn mark(grid: &mut Grid<char>, cell: (usize, usize), direction: Direction) {
+    let glyph = match direction {
+        Direction::Up | Direction::Down => '|',
+        Direction::Left | Direction::Right => '-',
+        Direction::UpRight | Direction::DownLeft => '/',
+        Direction::DownRight | Direction::UpLeft => '\\',
+    };
+
+    let current = &mut grid[cell];
+    *current = match *current {
+        c if PATH.contains(&c) && c != glyph => '+',
+        _ => glyph,
+    };
+}
+
+/// Plays frames in the terminal, drawing each one over the previous one and
+/// waiting `delay` in between. Frames are rendered one at a time, so that
+/// long simulations can be played without keeping them all.
+///
+/// Returns the number of frames played.
+pub fn play<F>(
+    out: &mut impl Write,
+    frames: impl IntoIterator<Item = F>,
+    delay: Duration,
+) -> io::Result<usize>
+where
+    F: Display,
+{
+    let mut count = 0;
+
+    for frame in frames {
+        if count > 0 {
+            thread::sleep(delay);
+        }
+
+        writeln!(out, "{CLEAR}{frame}")?;
+        out.flush()?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark() {
+        let mut grid = Grid::new(3, 3, '.');
+
+        mark(&mut grid, (1, 0), Direction::Right);
+        mark(&mut grid, (1, 1), Direction::Right);
+        mark(&mut grid, (1, 1), Direction::Up);
+        mark(&mut grid, (0, 1), Direction::Up);
+        mark(&mut grid, (0, 1), Direction::Down);
+        mark(&mut grid, (2, 2), Direction::DownRight);
+
+        assert_eq!(grid.to_string(), ".|.\n-+.\n..\\");
+    }
+
+    #[test]
+    fn test_play() {
+        let mut out = Vec::new();
+
+        let count = play(&mut out, ["ab", "cd"], Duration::ZERO).unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{CLEAR}ab\n{CLEAR}cd\n")
+        );
+    }
+}
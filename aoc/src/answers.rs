@@ -0,0 +1,205 @@
+use crate::{Error, Part, data};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// The accepted answers of a day.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(
+        default,
+        deserialize_with = "deserialize_answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part1: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part2: Option<String>,
+}
+
+/// Answers may be written as strings or as plain numbers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Text(String),
+    Unsigned(u64),
+    Signed(i64),
+}
+
+fn deserialize_answer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Some(match RawAnswer::deserialize(deserializer)? {
+        RawAnswer::Text(answer) => answer,
+        RawAnswer::Unsigned(answer) => answer.to_string(),
+        RawAnswer::Signed(answer) => answer.to_string(),
+    }))
+}
+
+/// The outcome of checking an answer against the accepted one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// No answer was accepted yet.
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// The accepted answers of a year, stored as a TOML or JSON file keyed by
+/// day, depending on its extension:
+///
+/// ```toml
+/// [1]
+/// part1 = 11
+/// part2 = "31"
+/// ```
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    /// Loads the answers stored in the given file, if it exists.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let days = data::read(&path)?.unwrap_or_default();
+
+        Ok(Self { path, days })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the accepted answer of the given part, if any.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    /// Sets the accepted answer of the given part. Call [`Answers::save`] to
+    /// write it to the file.
+    pub fn set(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        let answers = self.days.entry(day).or_default();
+
+        match part {
+            Part::One => answers.part1 = Some(answer.into()),
+            Part::Two => answers.part2 = Some(answer.into()),
+        }
+    }
+
+    /// Compares an answer to the accepted one.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        }
+    }
+
+    /// Writes the answers to the file, in the format given by its extension.
+    pub fn save(&self) -> Result<(), Error> {
+        data::write(&self.path, &self.days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc_test_answers_{name}"));
+        let _ = std::fs::remove_file(&path);
+
+        path
+    }
+
+    #[test]
+    fn test_load_toml() {
+        let path = temp_path("load.toml");
+        std::fs::write(&path, "[1]\npart1 = 11\npart2 = \"31\"\n\n[5]\npart1 = 3\n").unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(answers.get(5, Part::One), Some("3"));
+        assert_eq!(answers.get(5, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_json() {
+        let path = temp_path("load.json");
+        std::fs::write(&path, r#"{"1": {"part1": 11, "part2": "31"}}"#).unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let path = temp_path("invalid.toml");
+        std::fs::write(&path, "[1]\npart1 = [11]\n").unwrap();
+
+        assert!(matches!(
+            Answers::load(&path),
+            Err(Error::InvalidFile { .. })
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::load(temp_path("check.toml")).unwrap();
+        answers.set(1, Part::One, "11");
+
+        assert_eq!(answers.check(1, Part::One, "11"), Status::Pass);
+        assert_eq!(answers.check(1, Part::One, "12"), Status::Fail);
+        assert_eq!(answers.check(1, Part::Two, "31"), Status::Missing);
+    }
+
+    #[test]
+    fn test_save() {
+        for name in ["save.toml", "save.json"] {
+            let mut answers = Answers::load(temp_path(name)).unwrap();
+            answers.set(4, Part::One, "18");
+            answers.set(4, Part::Two, "9");
+            answers.set(12, Part::One, "1930");
+            answers.save().unwrap();
+
+            let reloaded = Answers::load(answers.path()).unwrap();
+
+            assert_eq!(reloaded.days, answers.days);
+
+            std::fs::remove_file(answers.path()).unwrap();
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{Error, Part, Puzzle, data};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// A timed step of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// Summary of the durations of several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes the given durations, which must not be empty.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No sample to summarize");
+        samples.sort();
+
+        // Nearest-rank percentiles.
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// The timing of a step, along with the answer it produced, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub step: Step,
+    pub answer: Option<String>,
+    pub stats: Stats,
+}
+
+/// Runs `f` `warmup` times, then times it over `iterations` runs.
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::new(samples)
+}
+
+/// Times the parsing of the input, then each of the given parts.
+pub fn bench(
+    puzzle: &Puzzle,
+    input: &str,
+    parts: &[Part],
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Timing>, Error> {
+    // Fail early rather than panicking while timing.
+    let parsed = puzzle.parse(input)?;
+
+    let mut timings = vec![Timing {
+        step: Step::Parse,
+        answer: None,
+        stats: measure(warmup, iterations, || puzzle.parse(input)),
+    }];
+
+    for &part in parts {
+        timings.push(Timing {
+            step: Step::Part(part),
+            answer: Some(puzzle.solve(&parsed, part)?),
+            stats: measure(warmup, iterations, || puzzle.solve(&parsed, part)),
+        });
+    }
+
+    Ok(timings)
+}
+
+/// The median durations of a previous run, in nanoseconds, stored as a TOML
+/// or JSON file keyed by year then day, depending on its extension:
+///
+/// ```toml
+/// [2024.6]
+/// parse = 18000
+/// part1 = 350000
+/// part2 = 2100000000
+/// ```
+#[derive(Debug)]
+pub struct Baseline {
+    path: PathBuf,
+    years: BTreeMap<u16, BTreeMap<u8, BTreeMap<String, u64>>>,
+}
+
+impl Baseline {
+    /// Loads the baseline stored in the given file, if it exists.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let years = data::read(&path)?.unwrap_or_default();
+
+        Ok(Self { path, years })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the median duration of the given step, if any.
+    pub fn get(&self, year: u16, day: u8, step: Step) -> Option<Duration> {
+        let nanos = self.years.get(&year)?.get(&day)?.get(&step.to_string())?;
+
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    /// Sets the median duration of the given step. Call [`Baseline::save`] to
+    /// write it to the file.
+    pub fn set(&mut self, year: u16, day: u8, step: Step, median: Duration) {
+        self.years
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(step.to_string(), median.as_nanos() as u64);
+    }
+
+    /// Returns the relative change of a median duration compared to the
+    /// baseline, e.g. `0.25` when it is 25% slower, if there is one.
+    pub fn change(&self, year: u16, day: u8, step: Step, median: Duration) -> Option<f64> {
+        let baseline = self.get(year, day, step)?;
+
+        Some(median.as_secs_f64() / baseline.as_secs_f64().max(f64::EPSILON) - 1.0)
+    }
+
+    /// Writes the baseline to the file, in the format given by its extension.
+    pub fn save(&self) -> Result<(), Error> {
+        data::write(&self.path, &self.years)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    struct Day00;
+
+    impl Solution for Day00 {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 0;
+
+        type Input = Vec<u64>;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| Error::parse(1, 1, "Not a number")))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<u64, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<u64, Error> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(10),
+                p95: Duration::from_millis(19),
+            }
+        );
+        assert_eq!(
+            Stats::new(vec![Duration::from_millis(3)]).p95,
+            Duration::from_millis(3)
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+
+        measure(2, 5, || calls += 1);
+
+        assert_eq!(calls, 7);
+    }
+
+    #[test]
+    fn test_bench() {
+        let puzzle = Puzzle::new::<Day00>();
+
+        let timings = bench(&puzzle, "2 3 4", &[Part::One, Part::Two], 1, 3).unwrap();
+        let steps: Vec<_> = timings
+            .iter()
+            .map(|t| (t.step, t.answer.as_deref()))
+            .collect();
+
+        assert_eq!(
+            steps,
+            vec![
+                (Step::Parse, None),
+                (Step::Part(Part::One), Some("9")),
+                (Step::Part(Part::Two), Some("24")),
+            ]
+        );
+        assert!(bench(&puzzle, "2 x", &[Part::One], 1, 3).is_err());
+    }
+
+    #[test]
+    fn test_baseline() {
+        // Unique to the process and the test, so that parallel runs do not
+        // share the file.
+        let path = std::env::temp_dir().join(format!(
+            "aoc_test_bench_{}_baseline.toml",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let mut baseline = Baseline::load(&path).unwrap();
+        let step = Step::Part(Part::One);
+        assert_eq!(
+            baseline.change(2024, 6, step, Duration::from_millis(1)),
+            None
+        );
+
+        baseline.set(2024, 6, step, Duration::from_millis(100));
+        baseline.save().unwrap();
+
+        let reloaded = Baseline::load(&path).unwrap();
+        assert_eq!(
+            reloaded.get(2024, 6, step),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            reloaded.change(2024, 6, step, Duration::from_millis(125)),
+            Some(0.25)
+        );
+        assert_eq!(reloaded.get(2024, 6, Step::Parse), None);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
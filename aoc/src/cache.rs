@@ -0,0 +1,158 @@
+use crate::{Error, Fetcher, load_input};
+use std::path::{Path, PathBuf};
+
+/// A local directory of puzzle inputs, stored as `<dir>/<year>/<day>.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the per-user cache directory, `$XDG_CACHE_HOME/aoc` or
+    /// `$HOME/.cache/aoc`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let env = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+        env("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env("HOME").map(|home| Path::new(&home).join(".cache")))
+            .map(|cache| cache.join("aoc"))
+    }
+
+    /// The root directory of the cache.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the path of the input of the given day.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// Returns the path of the answers submitted for the given day.
+    pub fn submissions_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.submissions.tsv"))
+    }
+
+    /// Returns the path of the accepted answers of the given year,
+    /// `<dir>/<year>/answers.json` if it exists, `answers.toml` otherwise.
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        let dir = self.dir.join(year.to_string());
+        let json = dir.join("answers.json");
+
+        match json.exists() {
+            true => json,
+            false => dir.join("answers.toml"),
+        }
+    }
+
+    /// Returns the path of the benchmark baseline, `<dir>/bench.toml`.
+    pub fn baseline_path(&self) -> PathBuf {
+        self.dir.join("bench.toml")
+    }
+
+    /// Reads the input of the given day. When it is not cached yet, it is
+    /// downloaded with the fetcher, if any, and stored for the next runs.
+    pub fn load(&self, year: u16, day: u8, fetcher: Option<&dyn Fetcher>) -> Result<String, Error> {
+        let path = self.input_path(year, day);
+
+        match (load_input(&path), fetcher) {
+            (Err(Error::MissingFile(_)), Some(fetcher)) => {
+                let input = fetcher.fetch_input(year, day)?;
+                self.store(&path, &input)?;
+
+                Ok(input)
+            }
+            (result, _) => result,
+        }
+    }
+
+    fn store(&self, path: &Path, input: &str) -> Result<(), Error> {
+        let unwritable = |source| Error::UnwritableFile {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(unwritable)?;
+        }
+
+        std::fs::write(path, input).map_err(unwritable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HttpFetcher;
+    use crate::mock::MockServer;
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = std::env::temp_dir().join(format!("aoc_test_cache_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_input_path() {
+        let cache = Cache::new("/home/elf/.cache/aoc");
+
+        assert_eq!(
+            cache.input_path(2025, 5),
+            PathBuf::from("/home/elf/.cache/aoc/2025/05.txt")
+        );
+    }
+
+    #[test]
+    fn test_load_fetches_once() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2024/day/11/input" => (200, "125 17\n".to_string()),
+            _ => (404, "Not found".to_string()),
+        });
+        let fetcher = HttpFetcher::new(server.url(), "secret");
+        let cache = temp_cache("fetches_once");
+
+        assert_eq!(cache.load(2024, 11, Some(&fetcher)).unwrap(), "125 17\n");
+        assert_eq!(cache.load(2024, 11, Some(&fetcher)).unwrap(), "125 17\n");
+
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(cache.input_path(2024, 11)).unwrap(),
+            "125 17\n"
+        );
+
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_load_without_fetcher() {
+        let cache = temp_cache("without_fetcher");
+
+        assert!(matches!(
+            cache.load(2024, 11, None),
+            Err(Error::MissingFile(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_failed_fetch_is_not_cached() {
+        let server = MockServer::start(|_| (500, "Oops".to_string()));
+        let fetcher = HttpFetcher::new(server.url(), "secret");
+        let cache = temp_cache("failed_fetch");
+
+        assert!(matches!(
+            cache.load(2024, 11, Some(&fetcher)),
+            Err(Error::Fetch { .. })
+        ));
+        assert!(!cache.input_path(2024, 11).exists());
+    }
+}
@@ -0,0 +1,62 @@
+//! Reading and writing the TOML or JSON data files, e.g. the answers,
+//! depending on their extension.
+
+use crate::Error;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// Reads a data file, or returns `None` if it does not exist.
+pub(crate) fn read<T>(path: &Path) -> Result<Option<T>, Error>
+where
+    T: DeserializeOwned,
+{
+    let content = match crate::load_input(path) {
+        Ok(content) => content,
+        Err(Error::MissingFile(_)) => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let data = match is_json(path) {
+        true => serde_json::from_str(&content).map_err(|e| e.to_string()),
+        false => toml::from_str(&content).map_err(|e| e.message().to_string()),
+    };
+
+    data.map(Some).map_err(|message| Error::InvalidFile {
+        path: path.to_path_buf(),
+        message,
+    })
+}
+
+/// Writes a data file, creating its directory if needed.
+pub(crate) fn write<T>(path: &Path, data: &T) -> Result<(), Error>
+where
+    T: Serialize,
+{
+    let content = match is_json(path) {
+        true => serde_json::to_string_pretty(data)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        false => toml::to_string(data).map_err(|e| e.to_string()),
+    }
+    .map_err(|message| Error::InvalidFile {
+        path: path.to_path_buf(),
+        message,
+    })?;
+
+    let unwritable = |source| Error::UnwritableFile {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(unwritable)?;
+    }
+
+    std::fs::write(path, content).map_err(unwritable)
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+}
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// Errors raised while loading, parsing or solving a puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// No input was given on the command line.
+    NoInput,
+    /// No directory was given nor found to cache the inputs, the answers,
+    /// the submissions or the benchmarks.
+    NoCacheDir,
+    /// The input file does not exist.
+    MissingFile(PathBuf),
+    /// The input file exists but could not be read.
+    UnreadableFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A file could not be written.
+    UnwritableFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A file other than the input, e.g. the answers, is malformed.
+    InvalidFile { path: PathBuf, message: String },
+    /// A command line argument does not fit the puzzle input.
+    InvalidArgument(String),
+    /// A request needs a session cookie, but none was given.
+    NoSession,
+    /// A request to the advent of code server failed.
+    Fetch { url: String, message: String },
+    /// The input is not valid UTF-8. Line and column point at the first
+    /// invalid byte, both starting at 1.
+    InvalidUtf8 {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+    },
+    /// The input could not be parsed by the puzzle. Line and column both
+    /// start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input has no answer for a part of the puzzle, e.g. because its
+    /// rules contradict each other.
+    NoAnswer(String),
+    /// A part of the puzzle could not be solved, `message` describing the
+    /// error it failed with.
+    Unsolved { part: String, message: String },
+}
+
+impl Error {
+    /// Builds a parse error at the given line and column.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Builds a parse error pointing at `token`, which must be a slice of
+    /// `line`. The column is computed from the position of `token` in `line`.
+    pub fn at(line_number: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line
+            .get(..offset)
+            .map_or(1, |before| before.chars().count() + 1);
+
+        Self::parse(line_number, column, message)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoInput => write!(f, "no puzzle input given, use `--input-file` or `--input`"),
+            Error::NoCacheDir => write!(
+                f,
+                "no cache directory found, use `--input-dir` or `AOC_CACHE_DIR`"
+            ),
+            Error::MissingFile(path) => {
+                write!(f, "input file `{}` does not exist", path.display())
+            }
+            Error::UnreadableFile { path, source } => {
+                write!(f, "cannot read input file `{}`: {source}", path.display())
+            }
+            Error::UnwritableFile { path, source } => {
+                write!(f, "cannot write file `{}`: {source}", path.display())
+            }
+            Error::InvalidFile { path, message } => {
+                write!(f, "invalid file `{}`: {message}", path.display())
+            }
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+            Error::NoSession => write!(
+                f,
+                "no session cookie given, use `--session` or `AOC_SESSION`"
+            ),
+            Error::Fetch { url, message } => write!(f, "request to {url} failed: {message}"),
+            Error::InvalidUtf8 { path, line, column } => write!(
+                f,
+                "input file `{}` is not valid UTF-8 (line {line}, column {column})",
+                path.display()
+            ),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            Error::NoAnswer(message) => write!(f, "no answer: {message}"),
+            Error::Unsolved { part, message } => write!(f, "part {part}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnreadableFile { source, .. } | Error::UnwritableFile { source, .. } => {
+                Some(source)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let line = "12-abc";
+        let token = &line[3..];
+
+        match Error::at(4, line, token, "Not a number") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (4, 4)),
+            error => panic!("Unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let error = Error::parse(2, 5, "End is not a number");
+
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 5: End is not a number"
+        );
+    }
+}
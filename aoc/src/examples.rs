@@ -0,0 +1,383 @@
+//! Puzzle examples extracted from the README of each day.
+//!
+//! An example is a fenced or indented code block preceded by an annotation
+//! giving its name and the expected answers, any of them being optional:
+//!
+//! ````markdown
+//! <!-- aoc:example database part1=3 part2=14 -->
+//! ```
+//! 3-5
+//! 10-14
+//! ```
+//! ````
+//!
+//! Code blocks without annotation, e.g. explanations, are ignored.
+
+use crate::{Error, Part, Solution};
+
+const ANNOTATION: &str = "<!-- aoc:example";
+
+/// An example input and its expected answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    /// The line of the annotation in the README, starting at 1.
+    pub line: usize,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// Returns the expected answer of the given part, if any.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Extracts every annotated example of a markdown document.
+pub fn examples(markdown: &str) -> Result<Vec<Example>, Error> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut examples = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+
+        let Some(annotation) = line.trim().strip_prefix(ANNOTATION) else {
+            continue;
+        };
+
+        let mut example = parse_annotation(index, line, annotation, examples.len() + 1)?;
+
+        // The code block must directly follow the annotation, blank lines
+        // aside.
+        while lines.get(index).is_some_and(|line| line.trim().is_empty()) {
+            index += 1;
+        }
+
+        let block = match lines.get(index) {
+            Some(line) if fence(line).is_some() => fenced_block(&lines, &mut index),
+            Some(line) if indentation(line).is_some() => indented_block(&lines, &mut index),
+            _ => None,
+        };
+
+        example.input = block.ok_or_else(|| {
+            Error::parse(
+                example.line,
+                1,
+                "Expected a code block after the example annotation",
+            )
+        })?;
+
+        examples.push(example);
+    }
+
+    Ok(examples)
+}
+
+/// Returns the input of the example with the given name.
+///
+/// # Panics
+///
+/// Panics if the document has no such example, as it is meant to be used in
+/// tests.
+pub fn example(markdown: &str, name: &str) -> String {
+    examples(markdown)
+        .unwrap_or_else(|error| panic!("Invalid examples: {error}"))
+        .into_iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("No example named `{name}`"))
+        .input
+}
+
+/// Solves every example of a README and compares the answers to the expected
+/// ones.
+///
+/// # Panics
+///
+/// Panics, listing every mismatch, if an example cannot be parsed or one of
+/// its answers is wrong, or if the README has no example with an answer.
+pub fn check<S>(readme: &str)
+where
+    S: Solution,
+{
+    let examples = examples(readme).unwrap_or_else(|error| panic!("Invalid README: {error}"));
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for example in &examples {
+        let input = match S::parse(&example.input) {
+            Ok(input) => input,
+            Err(error) => {
+                failures.push(format!("example `{}`: {error}", example.name));
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = example.answer(part) else {
+                continue;
+            };
+
+            let actual = match part {
+                Part::One => S::part1(&input).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&input).map(|answer| answer.to_string()),
+            };
+            let actual = actual.unwrap_or_else(|error| format!("error: {error}"));
+
+            if actual != expected {
+                failures.push(format!(
+                    "example `{}`, part {part}: expected {expected}, got {actual}",
+                    example.name
+                ));
+            }
+
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "The README has no example with an answer");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Reads `name part1=... part2=...`, the rest of an annotation line.
+fn parse_annotation(
+    line_number: usize,
+    line: &str,
+    annotation: &str,
+    index: usize,
+) -> Result<Example, Error> {
+    let annotation = annotation
+        .trim_end()
+        .strip_suffix("-->")
+        .ok_or_else(|| Error::at(line_number, line, annotation, "Unclosed annotation"))?;
+
+    let mut example = Example {
+        name: format!("example{index}"),
+        line: line_number,
+        input: String::new(),
+        part1: None,
+        part2: None,
+    };
+
+    for (position, token) in annotation.split_whitespace().enumerate() {
+        match token.split_once('=') {
+            Some(("part1", answer)) => example.part1 = Some(answer.to_string()),
+            Some(("part2", answer)) => example.part2 = Some(answer.to_string()),
+            None if position == 0 => example.name = token.to_string(),
+            _ => {
+                return Err(Error::at(
+                    line_number,
+                    line,
+                    token,
+                    "Expected `part1=<answer>` or `part2=<answer>`",
+                ));
+            }
+        }
+    }
+
+    Ok(example)
+}
+
+/// Returns the fence opening or closing a code block, e.g. "```".
+fn fence(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let marker = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = line.chars().take_while(|c| *c == marker).count();
+
+    (length >= 3).then(|| &line[..length])
+}
+
+/// Returns the content of a line indented by four spaces or a tab.
+fn indentation(line: &str) -> Option<&str> {
+    line.strip_prefix("    ")
+        .or_else(|| line.strip_prefix('\t'))
+}
+
+/// Reads the fenced block starting at `index`, up to its closing fence.
+fn fenced_block(lines: &[&str], index: &mut usize) -> Option<String> {
+    let opening = fence(lines[*index])?;
+    let end = *index
+        + 1
+        + lines[*index + 1..]
+            .iter()
+            .position(|line| fence(line).is_some_and(|closing| closing.starts_with(opening)))?;
+
+    let block = to_input(&lines[*index + 1..end]);
+    *index = end + 1;
+
+    Some(block)
+}
+
+/// Reads the indented block starting at `index`, up to the first line that
+/// is neither indented nor blank.
+fn indented_block(lines: &[&str], index: &mut usize) -> Option<String> {
+    let mut block = Vec::new();
+
+    while let Some(line) = lines.get(*index) {
+        match indentation(line) {
+            Some(content) => block.push(content),
+            None if line.trim().is_empty() => block.push(""),
+            None => break,
+        }
+
+        *index += 1;
+    }
+
+    while block.last().is_some_and(|line| line.is_empty()) {
+        block.pop();
+    }
+
+    Some(to_input(&block))
+}
+
+/// Joins the lines of a block, ending with a newline like the puzzle inputs.
+fn to_input(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Generates a test solving every example of the README of the crate, see
+/// [`examples::check`](crate::examples::check).
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     aoc::readme_tests!(Day05);
+/// }
+/// ```
+#[macro_export]
+macro_rules! readme_tests {
+    ($solution:ty) => {
+        #[test]
+        fn readme_examples() {
+            $crate::examples::check::<$solution>(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/README.md"
+            )));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Day 0
+
+For example:
+
+<!-- aoc:example part1=3 -->
+```text
+1
+2
+```
+
+Explanation, ignored:
+
+    1 + 2 = 3
+
+<!-- aoc:example squares part2=5 -->
+
+    1
+    2
+
+~~~
+not an example
+~~~
+";
+
+    /// Sums the numbers for part 1, and their squares for part 2.
+    struct Day00;
+
+    impl Solution for Day00 {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| {
+                    line.parse()
+                        .map_err(|_| Error::parse(index + 1, 1, "Not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<u32, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<u32, Error> {
+            Ok(input.iter().map(|n| n * n).sum())
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = examples(README).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "example1".to_string(),
+                    line: 5,
+                    input: "1\n2\n".to_string(),
+                    part1: Some("3".to_string()),
+                    part2: None,
+                },
+                Example {
+                    name: "squares".to_string(),
+                    line: 15,
+                    input: "1\n2\n".to_string(),
+                    part1: None,
+                    part2: Some("5".to_string()),
+                }
+            ]
+        );
+        assert_eq!(example(README, "squares"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_invalid_annotations() {
+        assert!(matches!(
+            examples("<!-- aoc:example\n1\n2"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            examples("text\n<!-- aoc:example part3=1 -->\n```\n1\n```"),
+            Err(Error::Parse {
+                line: 2,
+                column: 18,
+                ..
+            })
+        ));
+        assert!(matches!(
+            examples("<!-- aoc:example -->\n\nNot a block\n"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check() {
+        check::<Day00>(README);
+    }
+
+    #[test]
+    #[should_panic(expected = "example `squares`, part 2: expected 6, got 5")]
+    fn test_check_wrong_answer() {
+        check::<Day00>(&README.replace("part2=5", "part2=6"));
+    }
+}
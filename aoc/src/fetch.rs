@@ -0,0 +1,105 @@
+use crate::Error;
+
+/// The advent of code website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The user agent sent along every request, as requested by the website.
+const USER_AGENT: &str = "github.com/tylp/advent-of-code";
+
+/// Downloads puzzle inputs.
+pub trait Fetcher {
+    /// Returns the input of the given day.
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, Error>;
+}
+
+/// Downloads puzzle inputs from an HTTP server, authenticated by a session
+/// cookie.
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Returns the URL of the given path on the server.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Sends a GET request and returns the response body.
+    pub(crate) fn get(&self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+
+        ureq::get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|error| Error::Fetch {
+                url,
+                message: error.to_string(),
+            })
+    }
+
+    /// Sends a form with a POST request and returns the response body.
+    pub(crate) fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = self.url(path);
+
+        ureq::post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|error| Error::Fetch {
+                url,
+                message: error.to_string(),
+            })
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    #[test]
+    fn test_fetch_input() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2025/day/5/input" => (200, "3-5\n10-14\n\n1\n5\n".to_string()),
+            _ => (404, "Not found".to_string()),
+        });
+
+        let fetcher = HttpFetcher::new(server.url(), "secret");
+        let input = fetcher.fetch_input(2025, 5).unwrap();
+
+        assert_eq!(input, "3-5\n10-14\n\n1\n5\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let server = MockServer::start(|_| (400, "Please log in".to_string()));
+        let fetcher = HttpFetcher::new(server.url(), "expired");
+
+        let error = fetcher.fetch_input(2025, 5).unwrap_err();
+
+        assert!(matches!(error, Error::Fetch { .. }));
+    }
+}
@@ -0,0 +1,363 @@
+//! Points and directions on a plane.
+//!
+//! The y axis points down, like the rows of a grid: [`Direction::Up`]
+//! decreases y and [`Direction::Right`] increases x.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Rem, Sub, SubAssign};
+
+/// A point, or a vector between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    /// Returns the distance to another point moving only horizontally or
+    /// vertically.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// Returns the distance to another point moving in any of the 8
+    /// directions, i.e. the largest of the distances along each axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        let (x, y) = (distance(self.x, other.x), distance(self.y, other.y));
+
+        if x > y { x } else { y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + Add<Output = T> + Rem<Output = T>,
+{
+    /// Wraps the point around the edges of a `width` by `height` area, e.g.
+    /// `(-1, 5)` becomes `(9, 0)` in a 10 by 5 area.
+    pub fn wrap(self, width: T, height: T) -> Self {
+        Self {
+            x: (self.x % width + width) % width,
+            y: (self.y % height + height) % height,
+        }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + TryInto<usize>,
+{
+    /// Returns the `(row, column)` of the point in a [`Grid`](crate::Grid),
+    /// if it is not negative.
+    pub fn cell(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+}
+
+impl<T> Point<T>
+where
+    T: TryFrom<usize>,
+{
+    /// Returns the point at the given `(row, column)` of a
+    /// [`Grid`](crate::Grid), if its coordinates fit in `T`.
+    pub fn from_cell((row, col): (usize, usize)) -> Option<Self> {
+        Some(Self {
+            x: col.try_into().ok()?,
+            y: row.try_into().ok()?,
+        })
+    }
+}
+
+fn distance<T>(a: T, b: T) -> T
+where
+    T: PartialOrd + Sub<Output = T>,
+{
+    if a > b { a - b } else { b - a }
+}
+
+impl<T> Add for Point<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T> Sub for Point<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> Mul<T> for Point<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T> AddAssign for Point<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T> SubAssign for Point<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Moves the point by one step in the direction.
+impl<T> Add<Direction> for Point<T>
+where
+    T: Add<Output = T> + From<i8>,
+{
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl<T> AddAssign<Direction> for Point<T>
+where
+    T: AddAssign + From<i8>,
+{
+    fn add_assign(&mut self, direction: Direction) {
+        *self += direction.offset();
+    }
+}
+
+impl<T> Display for Point<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the 4 orthogonal or 4 diagonal directions, listed clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from [`Direction::Up`].
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The 8 directions, clockwise from [`Direction::Up`].
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Returns the direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the direction after a quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the vector of a step in the direction.
+    pub fn offset<T>(self) -> Point<T>
+    where
+        T: From<i8>,
+    {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        };
+
+        Point::new(T::from(x), T::from(y))
+    }
+
+    /// Returns the arrow drawing the direction, e.g. `^` or `↗`.
+    pub fn glyph(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+
+    /// Turns clockwise by the given number of eighths of a turn.
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+}
+
+impl From<Direction> for char {
+    fn from(direction: Direction) -> Self {
+        direction.glyph()
+    }
+}
+
+/// Reads the arrow drawing a direction, see [`Direction::glyph`].
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(glyph: char) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.glyph() == glyph)
+            .ok_or_else(|| format!("{glyph:?} is not a direction"))
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(b * 2, Point::new(8, -4));
+        assert_eq!(a + Direction::Up, Point::new(1, 1));
+        assert_eq!(a + Direction::DownLeft, Point::new(0, 3));
+
+        let mut c = a;
+        c += Direction::Right;
+        c -= b;
+        assert_eq!(c, Point::new(-2, 4));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1_i64, 2);
+        let b = Point::new(4, -2);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::new(3_u8, 1).manhattan(Point::new(1, 4)), 5);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(Point::new(-1, 5).wrap(10, 5), Point::new(9, 0));
+        assert_eq!(Point::new(23, -11).wrap(10, 5), Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_cell() {
+        assert_eq!(Point::new(3, 1).cell(), Some((1, 3)));
+        assert_eq!(Point::new(-1, 1).cell(), None);
+        assert_eq!(Point::<i16>::from_cell((1, 3)), Some(Point::new(3, 1)));
+        assert_eq!(Point::<i8>::from_cell((200, 3)), None);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert_eq!(Direction::DownLeft.reverse(), Direction::UpRight);
+        assert!(Direction::UpLeft.is_diagonal());
+        assert!(!Direction::Down.is_diagonal());
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset::<i8>() + direction.reverse().offset(),
+                Point::default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_glyphs() {
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert_eq!(Direction::try_from('↖'), Ok(Direction::UpLeft));
+        assert!(Direction::try_from('x').is_err());
+        assert_eq!(char::from(Direction::Left), '<');
+
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(direction.glyph()), Ok(direction));
+        }
+    }
+}
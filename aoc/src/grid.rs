@@ -0,0 +1,468 @@
+use crate::Error;
+use crate::geom::Direction;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Cells are addressed by `(row, column)`, both starting at 0 from the
+/// top-left corner. A grid is parsed from text with one cell per character,
+/// the cells being converted with `From<char>` or `TryFrom<char>`:
+///
+/// ```
+/// use aoc::Grid;
+///
+/// let grid: Grid<char> = "#..\n.#.".parse().unwrap();
+///
+/// assert_eq!(grid.get((1, 1)), Some(&'#'));
+/// assert_eq!(grid.get((2, 0)), None);
+/// assert_eq!(grid.to_string(), "#..\n.#.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid filled with the given value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at the given position, if it is in the grid.
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    /// Returns the cell at the given position mutably, if it is in the grid.
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Returns the position reached by moving from `position` by
+    /// `(rows, columns)`, if it is in the grid.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (rows, cols): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let position = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+
+        self.index_of(position).map(|_| position)
+    }
+
+    /// Iterates over the positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |index| (index / width, index % width))
+    }
+
+    /// Iterates over the cells of the grid along with their position, row by
+    /// row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns a row of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "Row {row} is out of the grid");
+
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Iterates over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Iterates over a column of the grid, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {col} is out of the grid");
+
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Iterates over the columns of the grid, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Iterates over the diagonals of the grid going down and right, from the
+    /// bottom-left corner to the top-right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.diagonal_count()).map(|index| {
+            let start = (
+                (self.height - 1).saturating_sub(index),
+                index.saturating_sub(self.height - 1),
+            );
+
+            self.line(start, (1, 1))
+        })
+    }
+
+    /// Iterates over the diagonals of the grid going down and left, from the
+    /// top-left corner to the bottom-right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.diagonal_count()).map(|index| {
+            let row = index.saturating_sub(self.width - 1);
+
+            self.line((row, index - row), (1, -1))
+        })
+    }
+
+    /// Returns the position reached by moving one step from `position` in
+    /// the given direction, if it is in the grid.
+    pub fn step(&self, position: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let offset = direction.offset::<isize>();
+
+        self.offset(position, (offset.y, offset.x))
+    }
+
+    /// Iterates over the in-grid positions of the 4 orthogonal neighbours of
+    /// a cell, clockwise from the top one.
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Iterates over the in-grid positions of the 8 neighbours of a cell,
+    /// diagonals included, clockwise from the top one.
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Returns the position of the first cell, row by row, equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the position of the first cell, row by row, matching the
+    /// predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Builds a grid of the same size by converting each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns the grid mirrored along its main diagonal, rows becoming
+    /// columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (col, row))
+    }
+
+    /// Returns the grid rotated 90 degrees clockwise.
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (self.height - 1 - col, row))
+    }
+
+    /// Returns the grid rotated 90 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (col, self.width - 1 - row))
+    }
+
+    /// Builds a grid of the given width, whose cell at each position is the
+    /// cell of `self` at `source(position)`.
+    fn rearrange(&self, width: usize, source: impl Fn((usize, usize)) -> (usize, usize)) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.cells.len().checked_div(width).unwrap_or(0);
+        let cells = (0..width * height)
+            .map(|index| self[source((index / width, index % width))].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Iterates over the cells from `start`, moving by `step` until leaving
+    /// the grid.
+    fn line(&self, start: (usize, usize), step: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.get(start).map(|_| start), move |&position| {
+            self.offset(position, step)
+        })
+        .map(|position| &self[position])
+    }
+
+    fn diagonal_count(&self) -> usize {
+        match self.cells.is_empty() {
+            true => 0,
+            false => self.width + self.height - 1,
+        }
+    }
+
+    fn index_of(&self, (row, col): (usize, usize)) -> Option<usize> {
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {position:?} is out of the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {position:?} is out of the grid"))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = Error;
+
+    /// Parses a grid with one cell per character, every line having the
+    /// same length.
+    fn from_str(input: &str) -> Result<Self, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let mut length = 0;
+
+            for (column, c) in line.chars().enumerate() {
+                let cell = T::try_from(c)
+                    .map_err(|error| Error::parse(index + 1, column + 1, error.to_string()))?;
+
+                cells.push(cell);
+                length += 1;
+            }
+
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(Error::parse(
+                        index + 1,
+                        length.min(width) + 1,
+                        format!("Expected {width} cells, found {length}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+/// Writes the grid back to text, one line per row.
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!("".parse::<Grid<char>>().unwrap().width(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        #[derive(Debug)]
+        struct Digit;
+
+        impl TryFrom<char> for Digit {
+            type Error = String;
+
+            fn try_from(c: char) -> Result<Self, String> {
+                c.is_ascii_digit()
+                    .then_some(Digit)
+                    .ok_or(format!("{c:?} is not a digit"))
+            }
+        }
+
+        assert!(matches!(
+            "12\n3".parse::<Grid<Digit>>(),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            "12\n3x".parse::<Grid<Digit>>(),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = grid();
+
+        grid[(0, 1)] = 'x';
+        *grid.get_mut((1, 2)).unwrap() = 'y';
+
+        assert_eq!(grid.to_string(), "axc\ndey");
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        let collect = |line: &mut dyn Iterator<Item = &char>| line.collect::<String>();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+            [(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+
+        assert_eq!(grid.step((1, 1), Direction::UpRight), Some((0, 2)));
+        assert_eq!(grid.step((1, 1), Direction::Down), None);
+        assert_eq!(grid.step((0, 0), Direction::Left), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = grid();
+
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.position(|c| *c > 'c'), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
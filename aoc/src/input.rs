@@ -0,0 +1,112 @@
+use crate::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file on disk.
+    File(PathBuf),
+    /// The standard input, until end of file.
+    Stdin,
+    /// A string given inline.
+    Inline(String),
+}
+
+impl Source {
+    /// Reads the whole input.
+    pub fn load(&self) -> Result<String, Error> {
+        match self {
+            Source::File(path) => load_input(path),
+            Source::Stdin => {
+                let path = Path::new("<stdin>");
+                let mut bytes = Vec::new();
+
+                std::io::stdin().read_to_end(&mut bytes).map_err(|source| {
+                    Error::UnreadableFile {
+                        path: path.to_path_buf(),
+                        source,
+                    }
+                })?;
+
+                decode(path, bytes)
+            }
+            Source::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+/// Reads the whole content of an input file.
+pub fn load_input(path: impl AsRef<Path>) -> Result<String, Error> {
+    let path = path.as_ref();
+
+    let bytes = std::fs::read(path).map_err(|source| match source.kind() {
+        std::io::ErrorKind::NotFound => Error::MissingFile(path.to_path_buf()),
+        _ => Error::UnreadableFile {
+            path: path.to_path_buf(),
+            source,
+        },
+    })?;
+
+    decode(path, bytes)
+}
+
+/// Decodes the UTF-8 content read from `path`.
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, Error> {
+    String::from_utf8(bytes).map_err(|error| {
+        let valid = &error.as_bytes()[..error.utf8_error().valid_up_to()];
+        // The valid prefix is guaranteed to be UTF-8.
+        let valid = std::str::from_utf8(valid).unwrap_or_default();
+        let line = valid.matches('\n').count() + 1;
+        let column = valid
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+
+        Error::InvalidUtf8 {
+            path: path.to_path_buf(),
+            line,
+            column,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_input_missing_file() {
+        let error = load_input("does/not/exist.txt").unwrap_err();
+
+        assert!(matches!(error, Error::MissingFile(_)));
+    }
+
+    #[test]
+    fn test_load_input_invalid_utf8() {
+        let path = std::env::temp_dir().join("aoc_test_load_input_invalid_utf8.txt");
+        std::fs::write(&path, b"1 2\n3 \xff4").unwrap();
+
+        let error = load_input(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            error,
+            Error::InvalidUtf8 {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_load_inline() {
+        let source = Source::Inline("3-5\n10-14".to_string());
+
+        assert_eq!(source.load().unwrap(), "3-5\n10-14");
+    }
+}
@@ -1,25 +1,293 @@
-use clap::Parser;
-use std::fs::File;
-use std::io::BufRead;
+use clap::{Parser, ValueEnum};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::Instant;
+
+pub mod animation;
+mod answers;
+pub mod bench;
+mod cache;
+mod data;
+mod error;
+pub mod examples;
+mod fetch;
+pub mod geom;
+mod grid;
+mod input;
+pub mod memo;
+#[cfg(test)]
+mod mock;
+pub mod ranges;
+mod registry;
+pub mod report;
+mod submit;
+
+pub use answers::{Answers, DayAnswers, Status};
+pub use cache::Cache;
+pub use error::Error;
+pub use fetch::{DEFAULT_BASE_URL, Fetcher, HttpFetcher};
+pub use grid::Grid;
+pub use input::{Source, load_input};
+pub use registry::{Parsed, Puzzle, find, puzzles};
+pub use report::{Format, Record};
+pub use submit::{Submission, Submissions, Verdict};
+
+#[doc(hidden)]
+pub use inventory;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
-    #[arg(short, long, required = true)]
-    pub input_file: String,
+    /// Puzzle input file, `-` to read it from stdin. Defaults to
+    /// `<INPUT_DIR>/<year>/<day>.txt`.
+    #[arg(short, long)]
+    pub input_file: Option<String>,
+
+    /// Puzzle input given inline, e.g. a short example.
+    #[arg(long, conflicts_with = "input_file")]
+    pub input: Option<String>,
+
+    /// Directory caching the puzzle inputs, one sub-directory per year.
+    /// Defaults to `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`.
+    #[arg(long, env = "AOC_CACHE_DIR")]
+    pub input_dir: Option<PathBuf>,
+
+    /// Session cookie used to download the inputs missing from the cache.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Base URL of the advent of code server.
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+
+    /// Only solve the given part.
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Output format of the results.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
 }
 
-/// Reads lines from a file and returns them as a vector of strings.
-pub fn init() -> Vec<String> {
+impl Args {
+    /// Returns the input explicitly given on the command line, if any.
+    pub fn source(&self) -> Option<Source> {
+        if let Some(input) = &self.input {
+            return Some(Source::Inline(input.clone()));
+        }
+
+        match self.input_file.as_deref() {
+            Some("-") => Some(Source::Stdin),
+            Some(input_file) => Some(Source::File(PathBuf::from(input_file))),
+            None => None,
+        }
+    }
+
+    /// Returns the input cache, if a cache directory could be found.
+    pub fn cache(&self) -> Option<Cache> {
+        self.input_dir
+            .clone()
+            .or_else(Cache::default_dir)
+            .map(Cache::new)
+    }
+
+    /// Returns the fetcher downloading the inputs, if a session is given.
+    pub fn fetcher(&self) -> Option<HttpFetcher> {
+        self.session
+            .as_ref()
+            .map(|session| HttpFetcher::new(&self.base_url, session))
+    }
+
+    /// Reads the input of the given day. Unless one was given on the command
+    /// line, it is read from the cache and downloaded when missing.
+    pub fn load(&self, year: u16, day: u8) -> Result<String, Error> {
+        if let Some(source) = self.source() {
+            return source.load();
+        }
+
+        let cache = self.cache().ok_or(Error::NoInput)?;
+        let fetcher = self.fetcher();
+
+        cache.load(year, day, fetcher.as_ref().map(|f| f as &dyn Fetcher))
+    }
+
+    /// Returns the parts to solve, both of them unless `--part` is given.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A puzzle solution for a given day.
+///
+/// The raw input is parsed once with [`Solution::parse`], then the parsed
+/// value is handed to both parts.
+pub trait Solution {
+    /// The year of the puzzle.
+    const YEAR: u16;
+    /// The day of the puzzle.
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    /// The answer of the first part.
+    type Part1: Display;
+    /// The answer of the second part.
+    type Part2: Display;
+
+    /// Parses the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Solves the first part of the puzzle, failing if the input has no
+    /// answer for it.
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error>;
+
+    /// Solves the second part of the puzzle, failing if the input has no
+    /// answer for it.
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error>;
+}
+
+/// Reads the input file given on the command line, solves the puzzle and
+/// prints the answers.
+///
+/// Exits with a non-zero code if the input cannot be loaded or parsed, or if
+/// a part cannot be solved.
+pub fn run<S>()
+where
+    S: Solution,
+    S::Input: 'static,
+{
     let args = Args::parse();
 
-    let file = File::open(args.input_file).unwrap();
-    let reader = std::io::BufReader::new(file);
-    let mut lines: Vec<String> = Vec::new();
+    if let Err(error) = solve(&Puzzle::new::<S>(), &args) {
+        exit_with(&error);
+    }
+}
+
+/// Solves the requested parts of a puzzle and prints the answers.
+pub fn solve(puzzle: &Puzzle, args: &Args) -> Result<(), Error> {
+    print_records(&solve_records(puzzle, args)?, args.format)
+}
+
+/// Prints the answers of solved parts in the given format, then returns the
+/// error of the first part that failed, if any.
+pub fn print_records(records: &[Record], format: Format) -> Result<(), Error> {
+    match format {
+        Format::Text => records.iter().for_each(|record| {
+            if let Some(answer) = &record.answer {
+                println!("Part {}: {answer}", record.part)
+            }
+        }),
+        format => report::print(records, format),
+    }
+
+    match records.iter().find(|record| record.error.is_some()) {
+        Some(record) => Err(Error::Unsolved {
+            part: record.part.clone(),
+            message: record.error.clone().unwrap_or_default(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Solves the requested parts of a puzzle, timing each of them. A part that
+/// fails gets an `error` record.
+pub fn solve_records(puzzle: &Puzzle, args: &Args) -> Result<Vec<Record>, Error> {
+    let buffer = args.load(puzzle.year, puzzle.day)?;
+    let input = puzzle.parse(&buffer)?;
+
+    Ok(solve_parts(puzzle.year, puzzle.day, args, |part| {
+        puzzle.solve(&input, part)
+    }))
+}
+
+/// Solves the requested parts of an input already parsed, e.g. with options
+/// of its own, timing each of them. A part that fails gets an `error` record.
+pub fn solve_parsed<S>(args: &Args, input: &S::Input) -> Vec<Record>
+where
+    S: Solution,
+{
+    solve_parts(S::YEAR, S::DAY, args, |part| match part {
+        Part::One => S::part1(input).map(|answer| answer.to_string()),
+        Part::Two => S::part2(input).map(|answer| answer.to_string()),
+    })
+}
+
+/// Solves the requested parts with `solve`, timing each of them.
+fn solve_parts(
+    year: u16,
+    day: u8,
+    args: &Args,
+    solve: impl Fn(Part) -> Result<String, Error>,
+) -> Vec<Record> {
+    args.parts()
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+
+            match solve(part) {
+                Ok(answer) => {
+                    let mut record = Record::new(year, day, part, "ok");
+                    record.answer = Some(answer);
+                    record.duration = Some(start.elapsed());
+                    record
+                }
+                Err(error) => {
+                    let mut record = Record::new(year, day, part, "error");
+                    record.error = Some(error.to_string());
+                    record
+                }
+            }
+        })
+        .collect()
+}
 
-    reader.lines().for_each(|line| {
-        lines.push(line.unwrap());
-    });
+/// Prints a diagnostic for the given error and exits with a non-zero code.
+pub fn exit_with(error: &Error) -> ! {
+    eprintln!("error: {error}");
+    std::process::exit(1);
+}
+
+/// Reads the whole input given on the command line.
+pub fn try_init_raw() -> Result<String, Error> {
+    Args::parse().source().ok_or(Error::NoInput)?.load()
+}
 
-    lines
+/// Reads the whole input given on the command line as a single string.
+///
+/// Exits with a non-zero code if the input cannot be read.
+pub fn init_raw() -> String {
+    try_init_raw().unwrap_or_else(|error| exit_with(&error))
+}
+
+/// Reads the lines of the input given on the command line.
+pub fn try_init() -> Result<Vec<String>, Error> {
+    Ok(try_init_raw()?.lines().map(String::from).collect())
+}
+
+/// Reads lines from a file and returns them as a vector of strings.
+///
+/// Exits with a non-zero code if the file cannot be read.
+pub fn init() -> Vec<String> {
+    try_init().unwrap_or_else(|error| exit_with(&error))
 }
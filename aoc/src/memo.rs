@@ -0,0 +1,177 @@
+//! Memoization of expensive, often recursive, computations.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// A cache of computed values, counting how often it saved a computation.
+///
+/// Recursive functions take the memo as a parameter, and compute their
+/// sub-problems with [`Memo::get_or_compute`]:
+///
+/// ```
+/// use aoc::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+///
+/// assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    stats: Stats,
+}
+
+/// How often a [`Memo`] had the requested value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of values found in the cache.
+    pub hits: u64,
+    /// The number of values computed.
+    pub misses: u64,
+}
+
+impl Stats {
+    /// Returns the share of the requests found in the cache, from 0 to 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value of the key, if any, without counting it in
+    /// the stats.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Returns the cached value of the key, or computes and caches it.
+    ///
+    /// `compute` is given the memo itself, to compute the sub-problems of a
+    /// recursive function. It is not called if the value is cached.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = compute(self, &key);
+        self.values.insert(key, value.clone());
+
+        value
+    }
+
+    /// Returns the number of cached values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Removes every cached value and resets the stats.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.stats = Stats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The number of paths from the top-left corner of a grid to each cell,
+    /// moving right or down.
+    fn paths(memo: &mut Memo<(u32, u32), u64>, row: u32, col: u32) -> u64 {
+        memo.get_or_compute((row, col), |memo, &(row, col)| match (row, col) {
+            (0, _) | (_, 0) => 1,
+            _ => paths(memo, row - 1, col) + paths(memo, row, col - 1),
+        })
+    }
+
+    #[test]
+    fn test_recursion() {
+        let mut memo = Memo::new();
+
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+        assert_eq!(memo.len(), 17 * 17 - 1);
+        assert_eq!(memo.get(&(1, 1)), Some(&2));
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 15 * 15,
+                misses: 17 * 17 - 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cached() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+
+        for _ in 0..3 {
+            let value = memo.get_or_compute("key", |_, key| {
+                calls += 1;
+                key.len()
+            });
+            assert_eq!(value, 3);
+        }
+
+        assert_eq!(calls, 1);
+        assert_eq!(
+            memo.stats().to_string(),
+            "2 hits, 1 misses (66.7% hit rate)"
+        );
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().hit_rate(), 0.0);
+    }
+}
@@ -0,0 +1,301 @@
+//! Sets of integers stored as sorted, disjoint inclusive ranges.
+
+use std::ops::RangeInclusive;
+
+/// An integer type usable as the bounds of a [`RangeSet`].
+pub trait Integer: Copy + Ord {
+    /// Returns the next integer, unless `self` is the largest one.
+    fn successor(self) -> Option<Self>;
+
+    /// Returns the previous integer, unless `self` is the smallest one.
+    fn predecessor(self) -> Option<Self>;
+
+    /// Returns the number of integers from `start` to `end`, both included,
+    /// `start` being at most `end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, stored as the sorted inclusive ranges, or spans, it
+/// covers. Overlapping and adjacent ranges are merged on insertion, so that
+/// membership is a binary search over the spans.
+///
+/// ```
+/// use aoc::ranges::RangeSet;
+///
+/// let set: RangeSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+///
+/// assert_eq!(set.spans().collect::<Vec<_>>(), [3..=5, 10..=20]);
+/// assert!(set.contains(17));
+/// assert!(!set.contains(8));
+/// assert_eq!(set.len(), 14);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    /// Sorted, disjoint and non-adjacent `(start, end)` pairs, both included.
+    spans: Vec<(T, T)>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { spans: Vec::new() }
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: Integer,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the integers of a range, merging it with the spans it overlaps
+    /// or touches. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        // The spans to merge are those ending at most one before `start`...
+        let first = self
+            .spans
+            .partition_point(|&(_, e)| e.successor().is_some_and(|next| next < start));
+        // ...and starting at most one after `end`.
+        let last = self
+            .spans
+            .partition_point(|&(s, _)| end.successor().is_none_or(|next| s <= next));
+
+        if first < last {
+            start = start.min(self.spans[first].0);
+            end = end.max(self.spans[last - 1].1);
+        }
+
+        self.spans.splice(first..last, [(start, end)]);
+    }
+
+    /// Returns whether the set contains the value, in O(log n) for n spans.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.spans.partition_point(|&(start, _)| start <= value);
+
+        index > 0 && self.spans[index - 1].1 >= value
+    }
+
+    /// Returns the number of integers in the set.
+    pub fn len(&self) -> u128 {
+        self.spans
+            .iter()
+            .map(|&(start, end)| T::count(start, end))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Iterates over the disjoint spans of the set, in increasing order.
+    pub fn spans(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.spans.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Returns the integers in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.spans());
+
+        union
+    }
+
+    /// Returns the integers in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.spans.get(i), other.spans.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+
+            if start <= end {
+                spans.push((start, end));
+            }
+
+            // The span ending first cannot overlap the following spans of
+            // the other set.
+            match e1 <= e2 {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        Self { spans }
+    }
+
+    /// Returns the integers in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let mut j = 0;
+
+        for &(start, end) in &self.spans {
+            // Skip the spans of `other` ending before this one.
+            while other.spans.get(j).is_some_and(|&(_, e)| e < start) {
+                j += 1;
+            }
+
+            // The start of the part of the span left to cut, if any.
+            let mut current = Some(start);
+
+            for &(s, e) in other.spans[j..].iter().take_while(|&&(s, _)| s <= end) {
+                let Some(from) = current else {
+                    break;
+                };
+
+                if let Some(to) = s.predecessor().filter(|&to| from <= to) {
+                    spans.push((from, to));
+                }
+
+                current = e.successor();
+            }
+
+            if let Some(from) = current.filter(|&from| from <= end) {
+                spans.push((from, end));
+            }
+        }
+
+        Self { spans }
+    }
+}
+
+impl<T> Extend<RangeInclusive<T>> for RangeSet<T>
+where
+    T: Integer,
+{
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T> FromIterator<RangeInclusive<T>> for RangeSet<T>
+where
+    T: Integer,
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn spans(set: &RangeSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.spans().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = set(&[10..=12, 1..=3]);
+        assert_eq!(spans(&ranges), [1..=3, 10..=12]);
+
+        // Adjacent ranges are merged.
+        ranges.insert(4..=5);
+        assert_eq!(spans(&ranges), [1..=5, 10..=12]);
+
+        // Empty ranges are ignored.
+        #[allow(clippy::reversed_empty_ranges)]
+        ranges.insert(8..=7);
+        assert_eq!(spans(&ranges), [1..=5, 10..=12]);
+
+        ranges.insert(7..=7);
+        assert_eq!(spans(&ranges), [1..=5, 7..=7, 10..=12]);
+
+        // A range covering several spans replaces them.
+        ranges.insert(0..=11);
+        assert_eq!(spans(&ranges), [0..=12]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut ranges: RangeSet<u64> = [0..=5, u64::MAX - 1..=u64::MAX].into_iter().collect();
+        ranges.insert(6..=u64::MAX - 2);
+
+        assert_eq!(ranges.spans().collect::<Vec<_>>(), [0..=u64::MAX]);
+        assert_eq!(ranges.len(), u64::MAX as u128 + 1);
+        assert!(ranges.contains(u64::MAX));
+    }
+
+    #[test]
+    fn test_contains() {
+        let ranges = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+
+        let fresh: Vec<_> = [1, 5, 8, 11, 17, 32]
+            .into_iter()
+            .filter(|&id| ranges.contains(id))
+            .collect();
+
+        assert_eq!(fresh, [5, 11, 17]);
+        assert_eq!(ranges.len(), 14);
+        assert!(!RangeSet::new().contains(0));
+        assert!(RangeSet::<u8>::new().is_empty());
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[0..=10, 20..=30, 40..=50]);
+        let b = set(&[5..=25, 28..=29, 45..=60]);
+
+        assert_eq!(spans(&a.union(&b)), [0..=30, 40..=60]);
+        assert_eq!(
+            spans(&a.intersection(&b)),
+            [5..=10, 20..=25, 28..=29, 45..=50]
+        );
+        assert_eq!(spans(&a.difference(&b)), [0..=4, 26..=27, 30..=30, 40..=44]);
+        assert_eq!(spans(&b.difference(&a)), [11..=19, 51..=60]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn test_operations_brute_force() {
+        let a = set(&[-3..=2, 5..=5, 7..=12, 15..=18]);
+        let b = set(&[-5..=-2, 0..=6, 9..=9, 11..=16, 20..=20]);
+
+        for value in -10..=25 {
+            let (in_a, in_b) = (a.contains(value), b.contains(value));
+
+            assert_eq!(a.union(&b).contains(value), in_a || in_b, "{value}");
+            assert_eq!(a.intersection(&b).contains(value), in_a && in_b, "{value}");
+            assert_eq!(a.difference(&b).contains(value), in_a && !in_b, "{value}");
+        }
+    }
+}
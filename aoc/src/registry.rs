@@ -0,0 +1,109 @@
+use crate::{Error, Part, Solution};
+use std::any::Any;
+
+/// A type-erased [`Solution`], registered with [`register!`](crate::register)
+/// so that a runner can solve any day without knowing its concrete types.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Box<dyn Any>, Error>,
+    part1: fn(&dyn Any) -> Result<String, Error>,
+    part2: fn(&dyn Any) -> Result<String, Error>,
+}
+
+/// The parsed input of a [`Puzzle`], to be handed back to [`Puzzle::solve`].
+pub struct Parsed(Box<dyn Any>);
+
+impl Puzzle {
+    pub const fn new<S>() -> Self
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    /// Parses the raw puzzle input.
+    pub fn parse(&self, input: &str) -> Result<Parsed, Error> {
+        (self.parse)(input).map(Parsed)
+    }
+
+    /// Solves the given part of the puzzle and returns the formatted answer.
+    pub fn solve(&self, input: &Parsed, part: Part) -> Result<String, Error> {
+        match part {
+            Part::One => (self.part1)(input.0.as_ref()),
+            Part::Two => (self.part2)(input.0.as_ref()),
+        }
+    }
+}
+
+fn parse<S>(input: &str) -> Result<Box<dyn Any>, Error>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1<S>(input: &dyn Any) -> Result<String, Error>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("Input was parsed by another puzzle");
+
+    S::part1(input).map(|answer| answer.to_string())
+}
+
+fn part2<S>(input: &dyn Any) -> Result<String, Error>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("Input was parsed by another puzzle");
+
+    S::part2(input).map(|answer| answer.to_string())
+}
+
+inventory::collect!(Puzzle);
+
+/// Returns every registered puzzle, sorted by year then day.
+pub fn puzzles() -> Vec<&'static Puzzle> {
+    let mut puzzles: Vec<&'static Puzzle> = inventory::iter::<Puzzle>.into_iter().collect();
+    puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+
+    puzzles
+}
+
+/// Returns the puzzle registered for the given year and day, if any.
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    inventory::iter::<Puzzle>
+        .into_iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// Registers a [`Solution`] so that it can be found by the runner.
+///
+/// ```ignore
+/// pub struct Day01;
+///
+/// aoc::register!(Day01);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::Puzzle::new::<$solution>()
+        }
+    };
+}
@@ -0,0 +1,160 @@
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// The output format of the results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// A JSON array of records.
+    Json,
+    /// CSV records, with a header line.
+    Csv,
+}
+
+/// A structured result of a run, check, benchmark or submission.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// The part, `1` or `2`, or the timed step, e.g. `parse`.
+    pub part: String,
+    pub answer: Option<String>,
+    /// The accepted answer, when checking.
+    pub expected: Option<String>,
+    /// In nanoseconds.
+    #[serde(serialize_with = "serialize_nanos")]
+    pub duration: Option<Duration>,
+    /// The outcome, e.g. `ok`, `pass` or `regression`.
+    pub status: String,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, part: impl Display, status: impl Display) -> Self {
+        Self {
+            year,
+            day,
+            part: part.to_string(),
+            answer: None,
+            expected: None,
+            duration: None,
+            status: status.to_string().to_lowercase(),
+            error: None,
+        }
+    }
+}
+
+fn serialize_nanos<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match duration {
+        Some(duration) => serializer.serialize_u128(duration.as_nanos()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Prints the records in a machine-readable format. Each command prints its
+/// own text, so nothing is printed for [`Format::Text`].
+pub fn print(records: &[Record], format: Format) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+/// Formats the records as a pretty-printed JSON array.
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Records are valid JSON")
+}
+
+/// Formats the records as CSV, with a header line.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("year,day,part,answer,expected,duration,status,error\n");
+
+    for record in records {
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            csv_field(&record.part),
+            record.answer.as_deref().map(csv_field).unwrap_or_default(),
+            record
+                .expected
+                .as_deref()
+                .map(csv_field)
+                .unwrap_or_default(),
+            record
+                .duration
+                .map(|duration| duration.as_nanos().to_string())
+                .unwrap_or_default(),
+            csv_field(&record.status),
+            record.error.as_deref().map(csv_field).unwrap_or_default(),
+        ];
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Quotes a field containing a separator, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, Status};
+
+    fn records() -> Vec<Record> {
+        let mut solved = Record::new(2024, 1, Part::One, Status::Pass);
+        solved.answer = Some("11".to_string());
+        solved.expected = Some("11".to_string());
+        solved.duration = Some(Duration::from_micros(12));
+
+        let mut failed = Record::new(2024, 2, Part::Two, "error");
+        failed.error = Some("parse error at line 1, column 3: \"x\", not a number".to_string());
+
+        vec![solved, failed]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records())).unwrap();
+
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "year": 2024,
+                "day": 1,
+                "part": "1",
+                "answer": "11",
+                "expected": "11",
+                "duration": 12000,
+                "status": "pass",
+                "error": null,
+            })
+        );
+        assert_eq!(json[1]["duration"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "year,day,part,answer,expected,duration,status,error\n\
+             2024,1,1,11,11,12000,pass,\n\
+             2024,2,2,,,,error,\"parse error at line 1, column 3: \"\"x\"\", not a number\"\n"
+        );
+    }
+}
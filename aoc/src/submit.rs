@@ -0,0 +1,366 @@
+use crate::{Error, HttpFetcher, Part};
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The response of the server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, try again after the delay.
+    Wait(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    /// The response page could not be understood.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned by the server.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Right
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(page).unwrap_or_default())
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the verdict is final for the submitted answer, and should
+    /// therefore be recorded.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+/// Reads the delay of a "You have 1m 20s left to wait" message.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .try_fold(0, |seconds, token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(seconds + value * 3600),
+                "m" => Some(seconds + value * 60),
+                "s" => Some(seconds + value),
+                _ => None,
+            }
+        })
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wait(delay) => write!(f, "wait {}s", delay.as_secs()),
+            Verdict::WrongLevel => write!(f, "wrong-level"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "right" => Ok(Verdict::Right),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            _ => Err(format!("Unknown verdict `{value}`")),
+        }
+    }
+}
+
+impl HttpFetcher {
+    /// Submits the answer of a puzzle part and returns the verdict.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, Error> {
+        let level = part.to_string();
+        let page = self.post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        Ok(Verdict::parse(&page))
+    }
+}
+
+/// A submitted answer and its final verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// The submissions of a day, stored as one tab-separated `part verdict answer`
+/// line per submission.
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Loads the submissions stored in the given file, if it exists.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+
+        let content = match crate::load_input(&path) {
+            Ok(content) => content,
+            Err(Error::MissingFile(_)) => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let submissions = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_submission(index + 1, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { path, submissions })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter()
+    }
+
+    /// Returns the accepted answer of the given part, if any.
+    pub fn solution(&self, part: Part) -> Option<&str> {
+        self.iter()
+            .find(|s| s.part == part && s.verdict == Verdict::Right)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Returns the verdict already known for the given answer, either because
+    /// it was submitted before, because another answer was accepted, or
+    /// because it lies beyond a submitted answer that was too high or too
+    /// low.
+    pub fn verdict(&self, part: Part, answer: &str) -> Option<Verdict> {
+        if let Some(solution) = self.solution(part) {
+            return match solution == answer {
+                true => Some(Verdict::Right),
+                false => Some(Verdict::Wrong),
+            };
+        }
+
+        let number = answer.parse::<i128>().ok();
+
+        for submission in self.iter().filter(|s| s.part == part) {
+            if submission.answer == answer {
+                return Some(submission.verdict);
+            }
+
+            let bound = submission.answer.parse::<i128>().ok();
+
+            match (submission.verdict, number, bound) {
+                (Verdict::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                    return Some(Verdict::TooHigh);
+                }
+                (Verdict::TooLow, Some(number), Some(bound)) if number <= bound => {
+                    return Some(Verdict::TooLow);
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Records a submission and appends it to the file.
+    pub fn record(&mut self, submission: Submission) -> Result<(), Error> {
+        let unwritable = |source| Error::UnwritableFile {
+            path: self.path.clone(),
+            source,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(unwritable)?;
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(unwritable)?;
+
+        writeln!(
+            file,
+            "{}\t{}\t{}",
+            submission.part, submission.verdict, submission.answer
+        )
+        .map_err(unwritable)?;
+
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+}
+
+fn parse_submission(line_number: usize, line: &str) -> Result<Submission, Error> {
+    let fields: Vec<&str> = line.splitn(3, '\t').collect();
+
+    let [part, verdict, answer] = fields[..] else {
+        return Err(Error::parse(
+            line_number,
+            1,
+            "Expected `part verdict answer`",
+        ));
+    };
+
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Err(Error::at(line_number, line, part, "Invalid part")),
+    };
+    let verdict = verdict
+        .parse()
+        .map_err(|e| Error::at(line_number, line, verdict, e))?;
+
+    Ok(Submission {
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer to decorating the North Pole.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 20s left to wait. <a href=\"/2025/day/4\">[Return to Day 4]</a></p></article>";
+
+    fn temp_submissions(name: &str) -> Submissions {
+        let path = std::env::temp_dir().join(format!("aoc_test_submissions_{name}.tsv"));
+        let _ = std::fs::remove_file(&path);
+
+        Submissions::load(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(Verdict::parse(RIGHT), Verdict::Right);
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck..."),
+            Verdict::Wrong
+        );
+        assert_eq!(Verdict::parse(WAIT), Verdict::Wait(Duration::from_secs(80)));
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(|request| match request.body.as_str() {
+            "level=2&answer=43" => (200, RIGHT.to_string()),
+            _ => (200, TOO_HIGH.to_string()),
+        });
+        let fetcher = HttpFetcher::new(server.url(), "secret");
+
+        assert_eq!(
+            fetcher.submit(2025, 4, Part::Two, "50").unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            fetcher.submit(2025, 4, Part::Two, "43").unwrap(),
+            Verdict::Right
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/4/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_known_verdicts() {
+        let mut submissions = temp_submissions("known_verdicts");
+
+        let submission = |part, answer: &str, verdict| Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        };
+
+        submissions
+            .record(submission(Part::One, "100", Verdict::TooHigh))
+            .unwrap();
+        submissions
+            .record(submission(Part::One, "10", Verdict::TooLow))
+            .unwrap();
+        submissions
+            .record(submission(Part::One, "42", Verdict::Wrong))
+            .unwrap();
+
+        assert_eq!(
+            submissions.verdict(Part::One, "100"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            submissions.verdict(Part::One, "150"),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(submissions.verdict(Part::One, "3"), Some(Verdict::TooLow));
+        assert_eq!(submissions.verdict(Part::One, "42"), Some(Verdict::Wrong));
+        assert_eq!(submissions.verdict(Part::One, "43"), None);
+        assert_eq!(submissions.verdict(Part::Two, "42"), None);
+
+        submissions
+            .record(submission(Part::One, "43", Verdict::Right))
+            .unwrap();
+        assert_eq!(submissions.solution(Part::One), Some("43"));
+        assert_eq!(submissions.verdict(Part::One, "43"), Some(Verdict::Right));
+        assert_eq!(submissions.verdict(Part::One, "44"), Some(Verdict::Wrong));
+
+        // The submissions are read back from the file.
+        let reloaded = Submissions::load(submissions.path()).unwrap();
+        assert_eq!(reloaded.iter().count(), 4);
+        assert_eq!(reloaded.solution(Part::One), Some("43"));
+
+        std::fs::remove_file(submissions.path()).unwrap();
+    }
+}
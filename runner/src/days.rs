@@ -0,0 +1,15 @@
+//! Links every day crate into the runner, so that their puzzles are part of
+//! the registry.
+
+use aoc2024_01 as _;
+use aoc2024_02 as _;
+use aoc2024_03 as _;
+use aoc2024_04 as _;
+use aoc2024_05 as _;
+use aoc2024_06 as _;
+use aoc2024_11 as _;
+use aoc2025_01 as _;
+use aoc2025_02 as _;
+use aoc2025_03 as _;
+use aoc2025_04 as _;
+use aoc2025_05 as _;
@@ -0,0 +1,518 @@
+use aoc::bench::Baseline;
+use aoc::{Answers, Error, Format, Part, Record, Status, Submission, Submissions, Verdict};
+use clap::{Parser, Subcommand};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+mod days;
+mod new;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Runs any registered advent of code puzzle", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Year of the puzzle. With `--all` or `--check`, only runs the days of
+    /// this year.
+    #[arg(short, long, required_unless_present_any = ["all", "check"])]
+    year: Option<u16>,
+
+    /// Day of the puzzle. With `--check`, only checks this day.
+    #[arg(short, long, required_unless_present_any = ["all", "check"], conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Runs every registered day in sequence.
+    #[arg(long, conflicts_with_all = ["input_file", "input"])]
+    all: bool,
+
+    /// Checks the answers of every registered day against the accepted ones,
+    /// stored in `<INPUT_DIR>/<year>/answers.toml` or `answers.json`.
+    #[arg(long, conflicts_with_all = ["all", "input_file", "input"])]
+    check: bool,
+
+    #[command(flatten)]
+    args: aoc::Args,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solves a part of a puzzle and submits the answer.
+    #[command(mut_arg("part", |part| part.required(true)))]
+    Submit {
+        /// Year of the puzzle.
+        #[arg(short, long)]
+        year: u16,
+
+        /// Day of the puzzle.
+        #[arg(short, long)]
+        day: u8,
+
+        #[command(flatten)]
+        args: aoc::Args,
+    },
+    /// Creates the crate of a new day and registers it with the runner.
+    New {
+        /// Year of the puzzle.
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+
+        /// Day of the puzzle.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Times the parsing and both parts of the registered days.
+    #[command(mut_arg("input_file", |arg| arg.requires("day")))]
+    #[command(mut_arg("input", |arg| arg.requires("day")))]
+    Bench {
+        /// Only times the days of this year.
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Only times this day.
+        #[arg(short, long, requires = "year")]
+        day: Option<u8>,
+
+        /// Number of timed runs of each step.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Number of untimed runs of each step before timing it.
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+
+        /// File of the median durations to compare with. Defaults to
+        /// `<INPUT_DIR>/bench.toml`.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Saves the median durations of this run as the new baseline.
+        #[arg(long)]
+        save: bool,
+
+        /// Slowdown over the baseline, in percent, flagged as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        #[command(flatten)]
+        args: aoc::Args,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Submit { year, day, args }) => {
+            let part = args.part.unwrap_or(Part::One);
+
+            match submit(year, day, part, &args) {
+                Ok(Verdict::Right) => {}
+                Ok(_) => std::process::exit(1),
+                Err(error) => aoc::exit_with(&error),
+            }
+        }
+        Some(Command::New { year, day }) => {
+            match new::workspace_root().and_then(|root| new::create(&root, year, day)) {
+                Ok(dir) => println!(
+                    "Created {}, run it with `cargo run -p aoc{year}_{day:02}`",
+                    dir.display()
+                ),
+                Err(error) => aoc::exit_with(&error),
+            }
+        }
+        Some(Command::Bench {
+            year,
+            day,
+            iterations,
+            warmup,
+            baseline,
+            save,
+            threshold,
+            args,
+        }) => {
+            let options = BenchOptions {
+                iterations,
+                warmup,
+                baseline,
+                save,
+                threshold,
+            };
+
+            match bench(year, day, &options, &args) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(error) => aoc::exit_with(&error),
+            }
+        }
+        None if cli.check => match check(cli.year, cli.day, &cli.args) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(error) => aoc::exit_with(&error),
+        },
+        None if cli.all => run_all(cli.year, &cli.args),
+        None => {
+            let (year, day) = (cli.year.unwrap(), cli.day.unwrap());
+
+            if let Err(error) = aoc::solve(find(year, day), &cli.args) {
+                aoc::exit_with(&error);
+            }
+        }
+    }
+}
+
+/// Returns the puzzle of the given day, or exits if none is registered.
+fn find(year: u16, day: u8) -> &'static aoc::Puzzle {
+    aoc::find(year, day).unwrap_or_else(|| {
+        eprintln!("error: no puzzle registered for {year} day {day:02}");
+        std::process::exit(1);
+    })
+}
+
+/// Runs every registered day, optionally restricted to a year.
+fn run_all(year: Option<u16>, args: &aoc::Args) {
+    let mut failed = false;
+    let mut records = Vec::new();
+
+    let puzzles = aoc::puzzles()
+        .into_iter()
+        .filter(|puzzle| year.is_none_or(|year| year == puzzle.year));
+
+    for puzzle in puzzles {
+        if args.format == Format::Text {
+            println!("#### {} day {:02} ####", puzzle.year, puzzle.day);
+        }
+
+        match aoc::solve_records(puzzle, args) {
+            Ok(solved) => {
+                failed |= solved.iter().any(|record| record.error.is_some());
+
+                match args.format {
+                    Format::Text => {
+                        if let Err(error) = aoc::print_records(&solved, Format::Text) {
+                            eprintln!("error: {error}");
+                        }
+                    }
+                    _ => records.extend(solved),
+                }
+            }
+            Err(error) => {
+                if args.format == Format::Text {
+                    eprintln!("error: {error}");
+                }
+
+                records.extend(error_records(puzzle, args, &error));
+                failed = true;
+            }
+        }
+    }
+
+    aoc::report::print(&records, args.format);
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Returns an `error` record for each requested part of a puzzle.
+fn error_records(puzzle: &aoc::Puzzle, args: &aoc::Args, error: &Error) -> Vec<Record> {
+    args.parts()
+        .into_iter()
+        .map(|part| {
+            let mut record = Record::new(puzzle.year, puzzle.day, part, "error");
+            record.error = Some(error.to_string());
+            record
+        })
+        .collect()
+}
+
+/// Solves a part and submits its answer, unless its verdict is already known.
+fn submit(year: u16, day: u8, part: Part, args: &aoc::Args) -> Result<Verdict, Error> {
+    let puzzle = find(year, day);
+    let input = puzzle.parse(&args.load(year, day)?)?;
+    let answer = puzzle.solve(&input, part)?;
+
+    let cache = args.cache().ok_or(Error::NoCacheDir)?;
+    let mut submissions = Submissions::load(cache.submissions_path(year, day))?;
+
+    let mut record = Record::new(year, day, part, "");
+    record.answer = Some(answer.clone());
+
+    if let Some(verdict) = submissions.verdict(part, &answer) {
+        match args.format {
+            Format::Text => {
+                println!("Answer {answer} was already submitted for part {part}: {verdict}")
+            }
+            format => {
+                record.status = verdict.to_string();
+                aoc::report::print(&[record], format);
+            }
+        }
+
+        return Ok(verdict);
+    }
+
+    let fetcher = args.fetcher().ok_or(Error::NoSession)?;
+    let verdict = fetcher.submit(year, day, part, &answer)?;
+
+    match args.format {
+        Format::Text => println!("Submitted {answer} for part {part}: {verdict}"),
+        format => {
+            record.status = verdict.to_string();
+            aoc::report::print(&[record], format);
+        }
+    }
+
+    if verdict == Verdict::Right {
+        let mut answers = Answers::load(cache.answers_path(year))?;
+        answers.set(day, part, &answer);
+        answers.save()?;
+    }
+
+    if verdict.is_final() {
+        submissions.record(Submission {
+            part,
+            answer,
+            verdict,
+        })?;
+    }
+
+    Ok(verdict)
+}
+
+/// A row of the `--check` table.
+struct Check {
+    year: u16,
+    day: u8,
+    part: Part,
+    expected: Option<String>,
+    actual: Result<(String, Duration), String>,
+    status: Status,
+}
+
+impl Check {
+    fn record(&self) -> Record {
+        let mut record = Record::new(self.year, self.day, self.part, self.status);
+        record.expected = self.expected.clone();
+
+        match &self.actual {
+            Ok((answer, duration)) => {
+                record.answer = Some(answer.clone());
+                record.duration = Some(*duration);
+            }
+            Err(error) => record.error = Some(error.clone()),
+        }
+
+        record
+    }
+}
+
+/// Solves the selected days and prints how their answers compare to the
+/// accepted ones. Returns whether none of them failed.
+fn check(year: Option<u16>, day: Option<u8>, args: &aoc::Args) -> Result<bool, Error> {
+    let cache = args.cache().ok_or(Error::NoCacheDir)?;
+    let mut answers = BTreeMap::new();
+    let mut checks = Vec::new();
+
+    let puzzles = aoc::puzzles().into_iter().filter(|puzzle| {
+        year.is_none_or(|year| year == puzzle.year) && day.is_none_or(|day| day == puzzle.day)
+    });
+
+    for puzzle in puzzles {
+        let answers = match answers.entry(puzzle.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Answers::load(cache.answers_path(puzzle.year))?),
+        };
+
+        let input = args
+            .load(puzzle.year, puzzle.day)
+            .and_then(|input| puzzle.parse(&input));
+
+        for part in args.parts() {
+            let expected = answers.get(puzzle.day, part).map(String::from);
+
+            let actual = input.as_ref().map_err(Error::to_string).and_then(|input| {
+                let start = Instant::now();
+                let actual = puzzle
+                    .solve(input, part)
+                    .map_err(|error| error.to_string())?;
+
+                Ok((actual, start.elapsed()))
+            });
+
+            let status = match &actual {
+                Ok((actual, _)) => answers.check(puzzle.day, part, actual),
+                Err(_) if expected.is_some() => Status::Fail,
+                Err(_) => Status::Missing,
+            };
+
+            checks.push(Check {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                expected,
+                actual,
+                status,
+            });
+        }
+    }
+
+    match args.format {
+        Format::Text => print_checks(&checks),
+        format => aoc::report::print(
+            &checks.iter().map(Check::record).collect::<Vec<_>>(),
+            format,
+        ),
+    }
+
+    Ok(checks.iter().all(|check| check.status != Status::Fail))
+}
+
+fn print_checks(checks: &[Check]) {
+    let width = |header: &str, column: fn(&Check) -> usize| {
+        checks
+            .iter()
+            .map(column)
+            .chain([header.len()])
+            .max()
+            .unwrap()
+    };
+    let expected = width("EXPECTED", |check| {
+        check.expected.as_deref().map_or(1, str::len)
+    });
+
+    println!("YEAR  DAY  PART  STATUS   {:expected$}  ACTUAL", "EXPECTED");
+
+    for check in checks {
+        println!(
+            "{}  {:>3}  {:>4}  {:7}  {:expected$}  {}",
+            check.year,
+            format!("{:02}", check.day),
+            check.part.to_string(),
+            check.status.to_string(),
+            check.expected.as_deref().unwrap_or("-"),
+            match &check.actual {
+                Ok((answer, _)) => answer.clone(),
+                Err(error) => format!("error: {error}"),
+            },
+        );
+    }
+
+    let count = |status| checks.iter().filter(|check| check.status == status).count();
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+}
+
+struct BenchOptions {
+    iterations: usize,
+    warmup: usize,
+    baseline: Option<PathBuf>,
+    save: bool,
+    threshold: f64,
+}
+
+/// Times the selected days and compares their median durations to the
+/// baseline. Returns whether every day ran without error nor regression.
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    options: &BenchOptions,
+    args: &aoc::Args,
+) -> Result<bool, Error> {
+    let path = match &options.baseline {
+        Some(path) => path.clone(),
+        None => args.cache().ok_or(Error::NoCacheDir)?.baseline_path(),
+    };
+    let mut baseline = Baseline::load(path)?;
+    let mut success = true;
+    let mut records = Vec::new();
+
+    let puzzles = aoc::puzzles().into_iter().filter(|puzzle| {
+        year.is_none_or(|year| year == puzzle.year) && day.is_none_or(|day| day == puzzle.day)
+    });
+
+    if args.format == Format::Text {
+        println!(
+            "YEAR  DAY  STEP   {:>10}  {:>10}  {:>10}  BASELINE",
+            "MIN", "MEDIAN", "P95"
+        );
+    }
+
+    for puzzle in puzzles {
+        let timings = args.load(puzzle.year, puzzle.day).and_then(|input| {
+            aoc::bench::bench(
+                puzzle,
+                &input,
+                &args.parts(),
+                options.warmup,
+                options.iterations,
+            )
+        });
+
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(error) => {
+                if args.format == Format::Text {
+                    eprintln!("error: {} day {:02}: {error}", puzzle.year, puzzle.day);
+                }
+
+                records.extend(error_records(puzzle, args, &error));
+                success = false;
+                continue;
+            }
+        };
+
+        for timing in timings {
+            let (step, stats) = (timing.step, timing.stats);
+            let change = baseline.change(puzzle.year, puzzle.day, step, stats.median);
+            let regression = change.is_some_and(|change| change * 100.0 > options.threshold);
+
+            let status = if regression { "regression" } else { "ok" };
+            let mut record = Record::new(puzzle.year, puzzle.day, step, status);
+            record.answer = timing.answer;
+            record.duration = Some(stats.median);
+            records.push(record);
+
+            success &= !regression;
+
+            if options.save {
+                baseline.set(puzzle.year, puzzle.day, step, stats.median);
+            }
+
+            if args.format != Format::Text {
+                continue;
+            }
+
+            println!(
+                "{}  {:>3}  {:5}  {:>10}  {:>10}  {:>10}  {}",
+                puzzle.year,
+                format!("{:02}", puzzle.day),
+                step.to_string(),
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.p95),
+                match change {
+                    Some(change) if regression => format!("{:+.1}% REGRESSION", change * 100.0),
+                    Some(change) => format!("{:+.1}%", change * 100.0),
+                    None => "-".to_string(),
+                }
+            );
+        }
+    }
+
+    aoc::report::print(&records, args.format);
+
+    if options.save {
+        baseline.save()?;
+    }
+
+    Ok(success)
+}
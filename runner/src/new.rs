@@ -0,0 +1,286 @@
+//! Scaffolding of new day crates.
+
+use aoc::Error;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc = { path = "../../aoc" }
+"#;
+
+const LIB_RS: &str = r#"use aoc::{Error, Solution};
+
+pub struct {Day};
+
+aoc::register!({Day});
+
+impl Solution for {Day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<usize, Error> {
+        Ok(0)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Once the examples of the README are annotated, test them all with
+    // `aoc::readme_tests!({Day});`.
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part1() {
+        let input = {Day}::parse(EXAMPLE).unwrap();
+        assert_eq!({Day}::part1(&input).unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part2() {
+        let input = {Day}::parse(EXAMPLE).unwrap();
+        assert_eq!({Day}::part2(&input).unwrap(), 0);
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use {crate}::{Day};
+
+fn main() {
+    aoc::run::<{Day}>();
+}
+"#;
+
+/// Returns the closest directory holding the workspace manifest, starting
+/// from the current one.
+pub fn workspace_root() -> Result<PathBuf, Error> {
+    let current = std::env::current_dir().map_err(|source| Error::UnreadableFile {
+        path: PathBuf::from("."),
+        source,
+    })?;
+
+    current
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| Error::MissingFile(current.join("Cargo.toml")))
+}
+
+/// Creates the crate of a day from the template, and registers it with the
+/// workspace and the runner. Returns the directory of the crate.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<PathBuf, Error> {
+    let name = format!("aoc{year}_{day:02}");
+    let dir = root.join(year.to_string()).join(&name);
+
+    let fill = |template: &str| {
+        template
+            .replace("{crate}", &name)
+            .replace("{Day}", &format!("Day{day:02}"))
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+    };
+
+    let unwritable = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| Error::UnwritableFile { path, source }
+    };
+
+    std::fs::create_dir_all(root.join(year.to_string())).map_err(unwritable(&dir))?;
+    // Fails if the day already exists, rather than overwriting it.
+    std::fs::create_dir(&dir).map_err(unwritable(&dir))?;
+    std::fs::create_dir(dir.join("src")).map_err(unwritable(&dir))?;
+
+    for (file, content) in [
+        ("Cargo.toml", fill(CARGO_TOML)),
+        ("README.md", format!("# Day {day}\n")),
+        ("src/lib.rs", fill(LIB_RS)),
+        ("src/main.rs", fill(MAIN_RS)),
+    ] {
+        let path = dir.join(file);
+        std::fs::write(&path, content).map_err(unwritable(&path))?;
+    }
+
+    edit(&root.join("Cargo.toml"), |manifest| {
+        add_member(manifest, &format!("\"{year}/*\""))
+    })?;
+    edit(&root.join("runner/Cargo.toml"), |manifest| {
+        insert_sorted(
+            manifest,
+            &format!("{name} = {{ path = \"../{year}/{name}\" }}"),
+            "aoc20",
+        )
+    })?;
+    edit(&root.join("runner/src/days.rs"), |days| {
+        insert_sorted(days, &format!("use {name} as _;"), "use aoc20")
+    })?;
+
+    Ok(dir)
+}
+
+/// Rewrites a file with the given function.
+fn edit(path: &Path, f: impl FnOnce(&str) -> String) -> Result<(), Error> {
+    let content = aoc::load_input(path)?;
+
+    std::fs::write(path, f(&content)).map_err(|source| Error::UnwritableFile {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Adds a member to the workspace, before the first one sorting after it.
+fn add_member(manifest: &str, member: &str) -> String {
+    let Some(start) = manifest.find("members = [") else {
+        return manifest.to_string();
+    };
+    let start = start + "members = [".len();
+    let end = start + manifest[start..].find(']').unwrap_or(0);
+
+    let mut members: Vec<&str> = manifest[start..end].split(", ").collect();
+
+    if members.contains(&member) {
+        return manifest.to_string();
+    }
+
+    let index = members
+        .iter()
+        .position(|m| *m > member)
+        .unwrap_or(members.len());
+    members.insert(index, member);
+
+    format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    )
+}
+
+/// Inserts a line among the sorted lines starting with `prefix`, after the
+/// last of them if it sorts after all of them.
+fn insert_sorted(content: &str, line: &str, prefix: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    if lines.contains(&line) {
+        return content.to_string();
+    }
+
+    let matching = |l: &&str| l.starts_with(prefix);
+    let index = match lines.iter().position(|l| matching(l) && *l > line) {
+        Some(index) => index,
+        None => lines
+            .iter()
+            .rposition(matching)
+            .map_or(lines.len(), |index| index + 1),
+    };
+    lines.insert(index, line);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\"2024/*\", \"2025/*\", \"aoc\", \"runner\"]\n";
+
+        assert_eq!(
+            add_member(manifest, "\"2026/*\""),
+            "[workspace]\nmembers = [\"2024/*\", \"2025/*\", \"2026/*\", \"aoc\", \"runner\"]\n"
+        );
+        assert_eq!(add_member(manifest, "\"2025/*\""), manifest);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let days = "//! Days.\n\nuse aoc2024_01 as _;\nuse aoc2025_01 as _;\n";
+
+        assert_eq!(
+            insert_sorted(days, "use aoc2024_02 as _;", "use aoc20"),
+            "//! Days.\n\nuse aoc2024_01 as _;\nuse aoc2024_02 as _;\nuse aoc2025_01 as _;\n"
+        );
+        assert_eq!(
+            insert_sorted(days, "use aoc2025_06 as _;", "use aoc20"),
+            "//! Days.\n\nuse aoc2024_01 as _;\nuse aoc2025_01 as _;\nuse aoc2025_06 as _;\n"
+        );
+        assert_eq!(
+            insert_sorted(days, "use aoc2024_01 as _;", "use aoc20"),
+            days
+        );
+    }
+
+    #[test]
+    fn test_create() {
+        // Unique to the process and the test, so that parallel runs do not
+        // share the workspace.
+        let root = std::env::temp_dir().join(format!("aoc_test_new_{}_create", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("runner/src")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"2025/*\", \"aoc\", \"runner\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\naoc = { path = \"../aoc\" }\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("runner/src/days.rs"), "//! Days.\n\n").unwrap();
+
+        let dir = create(&root, 2026, 3).unwrap();
+
+        assert_eq!(dir, root.join("2026/aoc2026_03"));
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("aoc::register!(Day03);"));
+        assert!(lib.contains("const YEAR: u16 = 2026;\n    const DAY: u8 = 3;"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("src/main.rs")).unwrap(),
+            "use aoc2026_03::Day03;\n\nfn main() {\n    aoc::run::<Day03>();\n}\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("runner/Cargo.toml")).unwrap(),
+            "[dependencies]\naoc = { path = \"../aoc\" }\naoc2026_03 = { path = \"../2026/aoc2026_03\" }\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("runner/src/days.rs")).unwrap(),
+            "//! Days.\n\nuse aoc2026_03 as _;\n"
+        );
+        assert!(
+            std::fs::read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .contains("[\"2025/*\", \"2026/*\", \"aoc\", \"runner\"]")
+        );
+
+        // An existing day is never overwritten.
+        assert!(matches!(
+            create(&root, 2026, 3),
+            Err(Error::UnwritableFile { .. })
+        ));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}